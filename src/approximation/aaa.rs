@@ -0,0 +1,280 @@
+use crate::interp::evaluate_barycentric;
+use crate::linalg::{eigenvalues, singular_value_decomposition, LuDecomposition};
+
+// Eigenvalues of the shifted and inverted pencil below this fraction of the largest one belong to roots at infinity
+const INFINITE_ROOT_TOLERANCE: f64 = 1e-10;
+
+/// Rational function in barycentric form r(x) = sum(w_j * f_j / (x - z_j)) / sum(w_j / (x - z_j))
+/// with support points z_j, values f_j and weights w_j. With k support points it is of type (k - 1, k - 1).
+/// Complex poles, residues and zeros are returned as (real, imaginary) pairs.
+#[derive(Debug, Clone)]
+pub struct BarycentricRational {
+    support_points: Vec<f64>,
+    values: Vec<f64>,
+    weights: Vec<f64>,
+    error: f64,
+}
+
+impl BarycentricRational {
+    /// Approximates sampled values with the AAA algorithm (adaptive Antoulas-Anderson).
+    /// The sample with the largest error becomes the next support point and the weights minimise the linearised
+    /// residual on the other samples, given by the smallest right singular vector of the Loewner matrix.
+    /// Stops once the error on the samples is below the tolerance relative to the largest value,
+    /// or when the degree reaches max_degree.
+    pub fn aaa(
+        points: &[f64],
+        values: &[f64],
+        tolerance: f64,
+        max_degree: usize,
+    ) -> Result<Self, String> {
+        let m = points.len();
+        if values.len() != m || m < 2 {
+            return Err(String::from(
+                "points and values must have the same length and contain at least two samples",
+            ));
+        }
+        if points.iter().chain(values).any(|v| !v.is_finite()) {
+            return Err(String::from("points and values must be finite"));
+        }
+        let mut sorted = points.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        if sorted.windows(2).any(|w| w[0] == w[1]) {
+            return Err(String::from("points must be distinct"));
+        }
+        if tolerance.is_nan() || tolerance < 0.0 {
+            return Err(String::from("Tolerance must be non-negative"));
+        }
+
+        let scale = values.iter().fold(0.0, |s: f64, v| s.max(v.abs()));
+        let mean = values.iter().sum::<f64>() / m as f64;
+        let mut approximation = vec![mean; m];
+        let mut is_support = vec![false; m];
+        let mut support: Vec<usize> = Vec::new();
+        let mut weights = Vec::new();
+        let mut error = f64::INFINITY;
+        // One sample is always left out of the support so that the least-squares problem is not empty
+        while support.len() <= max_degree && support.len() + 1 < m {
+            let next = (0..m)
+                .filter(|&i| !is_support[i])
+                .max_by(|&i, &j| {
+                    (values[i] - approximation[i])
+                        .abs()
+                        .total_cmp(&(values[j] - approximation[j]).abs())
+                })
+                .unwrap();
+            is_support[next] = true;
+            support.push(next);
+
+            let rest: Vec<usize> = (0..m).filter(|&i| !is_support[i]).collect();
+            let loewner: Vec<Vec<f64>> = rest
+                .iter()
+                .map(|&i| {
+                    support
+                        .iter()
+                        .map(|&j| (values[i] - values[j]) / (points[i] - points[j]))
+                        .collect()
+                })
+                .collect();
+            let (singular_values, v) = singular_value_decomposition(&loewner);
+            let smallest = (0..support.len())
+                .min_by(|&i, &j| singular_values[i].total_cmp(&singular_values[j]))
+                .unwrap();
+            weights = v.iter().map(|row| row[smallest]).collect();
+
+            let nodes: Vec<f64> = support.iter().map(|&j| points[j]).collect();
+            let node_values: Vec<f64> = support.iter().map(|&j| values[j]).collect();
+            error = 0.0;
+            for &i in &rest {
+                approximation[i] = evaluate_barycentric(&nodes, &node_values, &weights, points[i]);
+                error = error.max((values[i] - approximation[i]).abs());
+            }
+            for &j in &support {
+                approximation[j] = values[j];
+            }
+            if error <= tolerance * scale {
+                break;
+            }
+        }
+
+        Ok(Self {
+            support_points: support.iter().map(|&j| points[j]).collect(),
+            values: support.iter().map(|&j| values[j]).collect(),
+            weights,
+            error,
+        })
+    }
+
+    /// Evaluates the rational function at x
+    pub fn evaluate(&self, x: f64) -> f64 {
+        evaluate_barycentric(&self.support_points, &self.values, &self.weights, x)
+    }
+
+    /// Returns the support points z_j
+    pub fn support_points(&self) -> &[f64] {
+        &self.support_points
+    }
+
+    /// Returns the values f_j at the support points
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Returns the barycentric weights w_j
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Returns the degree of the numerator and the denominator
+    pub fn degree(&self) -> usize {
+        self.support_points.len() - 1
+    }
+
+    /// Returns the largest absolute error on the samples left out of the support
+    pub fn error(&self) -> f64 {
+        self.error
+    }
+
+    /// Computes the poles, the roots of the denominator sum(w_j / (x - z_j)), sorted by real part
+    pub fn poles(&self) -> Result<Vec<(f64, f64)>, String> {
+        self.roots(&self.weights)
+    }
+
+    /// Computes the residues of the simple poles in the order of `poles`
+    pub fn residues(&self) -> Result<Vec<(f64, f64)>, String> {
+        let poles = self.poles()?;
+        Ok(poles
+            .iter()
+            .map(|&pole| {
+                // Residue of n(x) / d(x) at a simple root of d is n / d'
+                let mut numerator = (0.0, 0.0);
+                let mut derivative = (0.0, 0.0);
+                for ((&z, &f), &w) in self
+                    .support_points
+                    .iter()
+                    .zip(&self.values)
+                    .zip(&self.weights)
+                {
+                    let inverse = complex_divide((1.0, 0.0), (pole.0 - z, pole.1));
+                    let squared = complex_multiply(inverse, inverse);
+                    numerator = (
+                        numerator.0 + w * f * inverse.0,
+                        numerator.1 + w * f * inverse.1,
+                    );
+                    derivative = (derivative.0 - w * squared.0, derivative.1 - w * squared.1);
+                }
+                complex_divide(numerator, derivative)
+            })
+            .collect())
+    }
+
+    /// Computes the zeros, the roots of the numerator sum(w_j * f_j / (x - z_j)), sorted by real part
+    pub fn zeros(&self) -> Result<Vec<(f64, f64)>, String> {
+        let coefficients: Vec<f64> = self
+            .weights
+            .iter()
+            .zip(&self.values)
+            .map(|(w, f)| w * f)
+            .collect();
+        self.roots(&coefficients)
+    }
+
+    /// Finds the roots of sum(c_j / (x - z_j)) as the finite eigenvalues of the pencil (E, B) with
+    /// E = [[0, c^T], [1, diag(z)]] and B = diag(0, 1, ..., 1). The pencil always has two eigenvalues at infinity,
+    /// they are deflated exactly by restricting it to the vectors orthogonal to c and projecting out the ones vector.
+    /// The reduced pencil (A, M) is shifted and inverted, each eigenvalue of (A - s * M)^-1 * M is 1 / (lambda - s)
+    /// and the zero ones are the roots at infinity left when the degree drops.
+    fn roots(&self, coefficients: &[f64]) -> Result<Vec<(f64, f64)>, String> {
+        let k = self.support_points.len();
+        if k < 2 || coefficients.iter().all(|&c| c == 0.0) {
+            return Ok(vec![]);
+        }
+        // Rows and columns 1.. of the reflections span the complements of the ones vector and of c
+        let left = householder(&vec![1.0; k]);
+        let right = householder(coefficients);
+        let mut a = vec![vec![0.0; k - 1]; k - 1];
+        let mut m = vec![vec![0.0; k - 1]; k - 1];
+        for i in 1..k {
+            for j in 1..k {
+                for (l, &z) in self.support_points.iter().enumerate() {
+                    a[i - 1][j - 1] += left[i][l] * z * right[l][j];
+                    m[i - 1][j - 1] += left[i][l] * right[l][j];
+                }
+            }
+        }
+
+        let lower = self
+            .support_points
+            .iter()
+            .fold(f64::INFINITY, |a, &z| a.min(z));
+        let upper = self
+            .support_points
+            .iter()
+            .fold(f64::NEG_INFINITY, |a, &z| a.max(z));
+        // The shift only has to avoid the roots, try another one if the first hits a root
+        let mut shifts = [0.618_033_988_749_895, -0.414_213_562_373_095]
+            .iter()
+            .map(|offset| (lower + upper) / 2.0 + offset * (upper - lower) / 2.0);
+        let (shift, lu) = loop {
+            let shift = shifts
+                .next()
+                .ok_or("Pencil is singular, the roots can't be computed")?;
+            let shifted: Vec<Vec<f64>> = a
+                .iter()
+                .zip(&m)
+                .map(|(a, m)| a.iter().zip(m).map(|(a, m)| a - shift * m).collect())
+                .collect();
+            if let Ok(lu) = LuDecomposition::new(&shifted) {
+                break (shift, lu);
+            }
+        };
+
+        let mut inverted = vec![vec![0.0; k - 1]; k - 1];
+        for j in 0..k - 1 {
+            let column: Vec<f64> = m.iter().map(|row| row[j]).collect();
+            for (row, value) in inverted.iter_mut().zip(lu.solve(&column)) {
+                row[j] = value;
+            }
+        }
+        let eigenvalues = eigenvalues(&inverted)?;
+        let largest = eigenvalues
+            .iter()
+            .fold(0.0, |m: f64, &(re, im)| m.max(re.hypot(im)));
+        let mut roots: Vec<(f64, f64)> = eigenvalues
+            .into_iter()
+            .filter(|&(re, im)| re.hypot(im) > INFINITE_ROOT_TOLERANCE * largest)
+            .map(|mu| {
+                let inverse = complex_divide((1.0, 0.0), mu);
+                (shift + inverse.0, inverse.1)
+            })
+            .collect();
+        roots.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        Ok(roots)
+    }
+}
+
+/// Builds the Householder reflection I - 2 v v^T / (v^T v) that maps the vector onto the first axis
+fn householder(a: &[f64]) -> Vec<Vec<f64>> {
+    let norm = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let mut v = a.to_vec();
+    v[0] += a[0].signum() * norm;
+    let length = v.iter().map(|x| x * x).sum::<f64>();
+    (0..a.len())
+        .map(|i| {
+            (0..a.len())
+                .map(|j| f64::from(i == j) - 2.0 * v[i] * v[j] / length)
+                .collect()
+        })
+        .collect()
+}
+
+fn complex_multiply(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+fn complex_divide(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let norm = b.0 * b.0 + b.1 * b.1;
+    (
+        (a.0 * b.0 + a.1 * b.1) / norm,
+        (a.1 * b.0 - a.0 * b.1) / norm,
+    )
+}
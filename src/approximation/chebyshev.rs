@@ -0,0 +1,265 @@
+use crate::linalg::eigenvalues;
+use std::f64::consts::PI;
+
+/// Chebyshev series approximation of a function on the interval [lower, upper].
+/// The function is represented as f(x) = sum(c_k * T_k(t)), where t is x mapped onto [-1, 1]
+#[derive(Debug, Clone)]
+pub struct Chebyshev {
+    coefficients: Vec<f64>,
+    lower: f64,
+    upper: f64,
+}
+
+impl Chebyshev {
+    /// Approximates the function with a Chebyshev series of the given degree
+    pub fn new(function: &dyn Fn(f64) -> f64, lower: f64, upper: f64, degree: usize) -> Self {
+        if lower >= upper {
+            panic!("The lower bound must be smaller than the upper bound.");
+        }
+        Self {
+            coefficients: sample_coefficients(function, lower, upper, degree + 1),
+            lower,
+            upper,
+        }
+    }
+
+    /// Approximates the function choosing the degree adaptively.
+    /// The number of Chebyshev points is doubled until the trailing coefficients decay below the
+    /// tolerance relative to the largest coefficient, then the negligible tail is chopped off.
+    pub fn adaptive(
+        function: &dyn Fn(f64) -> f64,
+        lower: f64,
+        upper: f64,
+        tolerance: f64,
+        max_degree: usize,
+    ) -> Result<Self, String> {
+        if lower >= upper {
+            return Err(String::from(
+                "The lower bound must be smaller than the upper bound.",
+            ));
+        }
+        let mut points = 17.min(max_degree + 1);
+        loop {
+            let coefficients = sample_coefficients(function, lower, upper, points);
+            if coefficients.iter().any(|c| !c.is_finite()) {
+                return Err(String::from("Function returned a non-finite value"));
+            }
+            let scale = coefficients.iter().fold(0.0, |m: f64, c| m.max(c.abs()));
+            let tail = (points / 8).max(2).min(points);
+            let threshold = tolerance * scale.max(f64::MIN_POSITIVE);
+            if coefficients[points - tail..]
+                .iter()
+                .all(|c| c.abs() <= threshold)
+            {
+                let length = coefficients
+                    .iter()
+                    .rposition(|c| c.abs() > threshold)
+                    .map_or(1, |k| k + 1);
+                return Ok(Self {
+                    coefficients: coefficients[..length].to_vec(),
+                    lower,
+                    upper,
+                });
+            }
+            if points > max_degree {
+                return Err(format!(
+                    "Coefficients did not decay below {} up to degree {}",
+                    tolerance, max_degree
+                ));
+            }
+            points = (2 * points - 1).min(max_degree + 1);
+        }
+    }
+
+    /// Creates a Chebyshev series from its coefficients
+    pub fn from_coefficients(coefficients: Vec<f64>, lower: f64, upper: f64) -> Self {
+        if coefficients.is_empty() {
+            panic!("At least one coefficient is required.");
+        }
+        if lower >= upper {
+            panic!("The lower bound must be smaller than the upper bound.");
+        }
+        Self {
+            coefficients,
+            lower,
+            upper,
+        }
+    }
+
+    /// Returns the Chebyshev coefficients
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Returns the degree of the series
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Returns the interval of the approximation
+    pub fn domain(&self) -> (f64, f64) {
+        (self.lower, self.upper)
+    }
+
+    /// Evaluates the series at x using the Clenshaw recurrence
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let t = self.map_to_unit(x);
+        let mut b1 = 0.0;
+        let mut b2 = 0.0;
+        for &c in self.coefficients[1..].iter().rev() {
+            let b0 = 2.0 * t * b1 - b2 + c;
+            b2 = b1;
+            b1 = b0;
+        }
+        t * b1 - b2 + self.coefficients[0]
+    }
+
+    /// Returns the derivative as a Chebyshev series
+    pub fn derivative(&self) -> Chebyshev {
+        let n = self.coefficients.len();
+        if n == 1 {
+            return Self::from_coefficients(vec![0.0], self.lower, self.upper);
+        }
+        // c'_{k-1} = c'_{k+1} + 2k * c_k, the constant term is halved at the end
+        let mut derivative = vec![0.0; n + 1];
+        for k in (1..n).rev() {
+            derivative[k - 1] = derivative[k + 1] + 2.0 * k as f64 * self.coefficients[k];
+        }
+        derivative[0] /= 2.0;
+        derivative.truncate(n - 1);
+        let scale = 2.0 / (self.upper - self.lower);
+        derivative.iter_mut().for_each(|c| *c *= scale);
+        Self::from_coefficients(derivative, self.lower, self.upper)
+    }
+
+    /// Returns the antiderivative as a Chebyshev series, normalised to vanish at the lower bound
+    pub fn integral(&self) -> Chebyshev {
+        let n = self.coefficients.len();
+        let c = |k: usize| self.coefficients.get(k).copied().unwrap_or(0.0);
+        let scale = (self.upper - self.lower) / 2.0;
+        let mut integral = vec![0.0; n + 1];
+        integral[1] = scale * (2.0 * c(0) - c(2)) / 2.0;
+        for (k, value) in integral.iter_mut().enumerate().skip(2) {
+            *value = scale * (c(k - 1) - c(k + 1)) / (2.0 * k as f64);
+        }
+        // T_k(-1) = (-1)^k
+        integral[0] = -integral
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(k, v)| if k % 2 == 0 { *v } else { -v })
+            .sum::<f64>();
+        Self::from_coefficients(integral, self.lower, self.upper)
+    }
+
+    /// Integrates the series over its whole interval
+    pub fn definite_integral(&self) -> f64 {
+        // Odd polynomials integrate to zero and the integral of T_k is 2 / (1 - k^2) for even k
+        let scale = (self.upper - self.lower) / 2.0;
+        scale
+            * self
+                .coefficients
+                .iter()
+                .enumerate()
+                .step_by(2)
+                .map(|(k, c)| 2.0 * c / (1.0 - (k * k) as f64))
+                .sum::<f64>()
+    }
+
+    /// Finds all real roots of the series on its interval as eigenvalues of the colleague matrix
+    pub fn roots(&self) -> Result<Vec<f64>, String> {
+        let scale = self
+            .coefficients
+            .iter()
+            .fold(0.0, |m: f64, c| m.max(c.abs()));
+        // Negligible leading coefficients would produce spurious roots far outside of the interval
+        let n = match self
+            .coefficients
+            .iter()
+            .rposition(|c| c.abs() > f64::EPSILON * scale)
+        {
+            Some(0) | None => return Ok(vec![]),
+            Some(n) => n,
+        };
+        let c = &self.coefficients[..=n];
+
+        // x * T_0 = T_1 and x * T_k = (T_{k-1} + T_{k+1}) / 2, T_n is eliminated using p(x) = 0
+        let mut colleague = vec![vec![0.0; n]; n];
+        for j in 0..n {
+            colleague[n - 1][j] = -c[j] / (2.0 * c[n]);
+        }
+        if n == 1 {
+            colleague[0][0] *= 2.0;
+        } else {
+            colleague[0][1] = 1.0;
+            for k in 1..n - 1 {
+                colleague[k][k - 1] = 0.5;
+                colleague[k][k + 1] = 0.5;
+            }
+            colleague[n - 1][n - 2] += 0.5;
+        }
+
+        let tolerance = 1e-8;
+        let derivative = self.derivative();
+        let mut roots: Vec<f64> = eigenvalues(&colleague)?
+            .into_iter()
+            .filter(|(re, im)| im.abs() < tolerance && re.abs() <= 1.0 + tolerance)
+            .map(|(re, _)| self.map_from_unit(re.clamp(-1.0, 1.0)))
+            .map(|x| self.polish_root(&derivative, x))
+            .collect();
+        roots.sort_by(|a, b| a.total_cmp(b));
+        roots.dedup_by(|a, b| (*a - *b).abs() < tolerance * (self.upper - self.lower));
+        Ok(roots)
+    }
+
+    /// Refines a root with a few Newton steps, keeping the root inside the interval
+    fn polish_root(&self, derivative: &Chebyshev, mut x: f64) -> f64 {
+        for _ in 0..3 {
+            let dfx = derivative.evaluate(x);
+            if dfx == 0.0 {
+                break;
+            }
+            let next = (x - self.evaluate(x) / dfx).clamp(self.lower, self.upper);
+            if self.evaluate(next).abs() >= self.evaluate(x).abs() {
+                break;
+            }
+            x = next;
+        }
+        x
+    }
+
+    fn map_to_unit(&self, x: f64) -> f64 {
+        (2.0 * x - self.lower - self.upper) / (self.upper - self.lower)
+    }
+
+    fn map_from_unit(&self, t: f64) -> f64 {
+        0.5 * (self.lower + self.upper) + 0.5 * (self.upper - self.lower) * t
+    }
+}
+
+/// Samples the function on Chebyshev points of the first kind and computes the coefficients via a discrete cosine transform
+fn sample_coefficients(
+    function: &dyn Fn(f64) -> f64,
+    lower: f64,
+    upper: f64,
+    points: usize,
+) -> Vec<f64> {
+    let values: Vec<f64> = (0..points)
+        .map(|k| {
+            let t = (PI * (k as f64 + 0.5) / points as f64).cos();
+            function(0.5 * (lower + upper) + 0.5 * (upper - lower) * t)
+        })
+        .collect();
+    let mut coefficients: Vec<f64> = (0..points)
+        .map(|j| {
+            2.0 / points as f64
+                * values
+                    .iter()
+                    .enumerate()
+                    .map(|(k, v)| v * (PI * j as f64 * (k as f64 + 0.5) / points as f64).cos())
+                    .sum::<f64>()
+        })
+        .collect();
+    coefficients[0] /= 2.0;
+    coefficients
+}
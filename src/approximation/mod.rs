@@ -0,0 +1,5 @@
+mod aaa;
+mod chebyshev;
+
+pub use aaa::BarycentricRational;
+pub use chebyshev::Chebyshev;
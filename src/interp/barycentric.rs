@@ -0,0 +1,15 @@
+/// Evaluates the barycentric rational function r(x) = sum(w_j * f_j / (x - z_j)) / sum(w_j / (x - z_j)).
+/// At a support point the formula is singular, the value there is f_j.
+pub(crate) fn evaluate_barycentric(nodes: &[f64], values: &[f64], weights: &[f64], x: f64) -> f64 {
+    let mut numerator = 0.0;
+    let mut denominator = 0.0;
+    for ((&z, &f), &w) in nodes.iter().zip(values).zip(weights) {
+        let dx = x - z;
+        if dx == 0.0 {
+            return f;
+        }
+        numerator += w * f / dx;
+        denominator += w / dx;
+    }
+    numerator / denominator
+}
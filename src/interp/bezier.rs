@@ -0,0 +1,284 @@
+use super::{InterpolationType, Interpolator};
+
+// Below this width the subdivision of a Bernstein polynomial stops and the interval is taken as a root
+const ROOT_TOLERANCE: f64 = 1e-14;
+
+/// Planar Bézier curve B(t) = sum(P_i * C(n, i) * t^i * (1 - t)^(n - i)) for t in [0, 1]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BezierCurve {
+    control_points: Vec<(f64, f64)>,
+}
+
+impl BezierCurve {
+    /// Creates a Bézier curve of degree control_points.len() - 1
+    pub fn new(control_points: Vec<(f64, f64)>) -> Result<Self, String> {
+        if control_points.is_empty() {
+            return Err(String::from("At least one control point is required"));
+        }
+        Ok(Self { control_points })
+    }
+
+    /// Returns the control points of the curve
+    pub fn control_points(&self) -> &[(f64, f64)] {
+        &self.control_points
+    }
+
+    /// Returns the degree of the curve
+    pub fn degree(&self) -> usize {
+        self.control_points.len() - 1
+    }
+
+    /// Evaluates the curve at t with the de Casteljau algorithm
+    pub fn evaluate(&self, t: f64) -> (f64, f64) {
+        let (left, _) = self.subdivide(t);
+        *left.control_points.last().unwrap()
+    }
+
+    /// Splits the curve at t into two curves of the same degree covering [0, t] and [t, 1]
+    pub fn subdivide(&self, t: f64) -> (BezierCurve, BezierCurve) {
+        let mut points = self.control_points.clone();
+        let mut left = Vec::with_capacity(points.len());
+        let mut right = Vec::with_capacity(points.len());
+        // Each level of the de Casteljau triangle gives the next control point of both halves
+        for level in (0..points.len()).rev() {
+            left.push(points[0]);
+            right.push(points[level]);
+            for i in 0..level {
+                points[i] = lerp(points[i], points[i + 1], t);
+            }
+        }
+        right.reverse();
+        (
+            BezierCurve {
+                control_points: left,
+            },
+            BezierCurve {
+                control_points: right,
+            },
+        )
+    }
+
+    /// Returns the same curve written with one more control point
+    pub fn elevate(&self) -> BezierCurve {
+        let n = self.control_points.len();
+        let points = &self.control_points;
+        let mut elevated = Vec::with_capacity(n + 1);
+        elevated.push(points[0]);
+        for i in 1..n {
+            let weight = i as f64 / n as f64;
+            elevated.push(lerp(points[i], points[i - 1], weight));
+        }
+        elevated.push(points[n - 1]);
+        BezierCurve {
+            control_points: elevated,
+        }
+    }
+
+    /// Returns the derivative dB/dt, a curve of one degree less
+    pub fn derivative(&self) -> BezierCurve {
+        let n = self.degree();
+        if n == 0 {
+            return BezierCurve {
+                control_points: vec![(0.0, 0.0)],
+            };
+        }
+        let control_points = self
+            .control_points
+            .windows(2)
+            .map(|w| (n as f64 * (w[1].0 - w[0].0), n as f64 * (w[1].1 - w[0].1)))
+            .collect();
+        BezierCurve { control_points }
+    }
+
+    /// Computes the tight bounding box ((x_min, y_min), (x_max, y_max)) of the curve,
+    /// the extremes are taken at the end points and where a coordinate of the derivative vanishes
+    pub fn bounding_box(&self) -> ((f64, f64), (f64, f64)) {
+        let derivative = self.derivative();
+        let mut parameters = vec![0.0, 1.0];
+        for coordinate in [
+            derivative.coordinate(|p| p.0),
+            derivative.coordinate(|p| p.1),
+        ] {
+            bernstein_roots(&coordinate, 0.0, 1.0, &mut parameters);
+        }
+
+        let mut lower = (f64::INFINITY, f64::INFINITY);
+        let mut upper = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for t in parameters {
+            let (x, y) = self.evaluate(t);
+            lower = (lower.0.min(x), lower.1.min(y));
+            upper = (upper.0.max(x), upper.1.max(y));
+        }
+        (lower, upper)
+    }
+
+    fn coordinate(&self, select: impl Fn(&(f64, f64)) -> f64) -> Vec<f64> {
+        self.control_points.iter().map(select).collect()
+    }
+}
+
+/// Collects the roots in [lower, upper] of the polynomial with the given Bernstein coefficients.
+/// The polynomial lies within the convex hull of its coefficients, so an interval whose coefficients
+/// share their sign has no root and the others are split in half until they are small enough.
+fn bernstein_roots(coefficients: &[f64], lower: f64, upper: f64, roots: &mut Vec<f64>) {
+    if coefficients.iter().all(|&c| c > 0.0) || coefficients.iter().all(|&c| c < 0.0) {
+        return;
+    }
+    if coefficients.iter().all(|&c| c == 0.0) {
+        // The coordinate is constant on the whole interval, the end points are enough
+        return;
+    }
+    if upper - lower < ROOT_TOLERANCE {
+        roots.push((lower + upper) / 2.0);
+        return;
+    }
+
+    let mut points = coefficients.to_vec();
+    let mut left = Vec::with_capacity(points.len());
+    let mut right = Vec::with_capacity(points.len());
+    for level in (0..points.len()).rev() {
+        left.push(points[0]);
+        right.push(points[level]);
+        for i in 0..level {
+            points[i] = (points[i] + points[i + 1]) / 2.0;
+        }
+    }
+    right.reverse();
+    let middle = (lower + upper) / 2.0;
+    bernstein_roots(&left, lower, middle, roots);
+    bernstein_roots(&right, middle, upper, roots);
+}
+
+fn lerp(a: (f64, f64), b: (f64, f64), t: f64) -> (f64, f64) {
+    (a.0 + t * (b.0 - a.0), a.1 + t * (b.1 - a.1))
+}
+
+/// Writes the curves as an SVG path, a curve that doesn't start where the previous one ended opens a new subpath.
+/// SVG paths draw lines, quadratic and cubic curves, so higher degrees are rejected.
+pub fn svg_path(curves: &[BezierCurve]) -> Result<String, String> {
+    write_path(
+        curves,
+        |command, points, path| {
+            let coordinates: Vec<String> =
+                points.iter().map(|(x, y)| format!("{} {}", x, y)).collect();
+            path.push(format!("{} {}", command, coordinates.join(" ")));
+        },
+        ["M", "L", "Q", "C"],
+    )
+}
+
+/// Writes the curves as PDF path construction operators, quadratic curves are elevated to cubic ones
+/// as PDF only draws lines and cubic curves. Higher degrees are rejected.
+pub fn pdf_path(curves: &[BezierCurve]) -> Result<String, String> {
+    let elevated: Vec<BezierCurve> = curves
+        .iter()
+        .map(|curve| {
+            if curve.degree() == 2 {
+                curve.elevate()
+            } else {
+                curve.clone()
+            }
+        })
+        .collect();
+    write_path(
+        &elevated,
+        |operator, points, path| {
+            let coordinates: Vec<String> =
+                points.iter().map(|(x, y)| format!("{} {}", x, y)).collect();
+            path.push(format!("{} {}", coordinates.join(" "), operator));
+        },
+        ["m", "l", "", "c"],
+    )
+}
+
+/// Emits the move, line, quadratic and cubic commands of the curves through the writer
+fn write_path(
+    curves: &[BezierCurve],
+    write: impl Fn(&str, &[(f64, f64)], &mut Vec<String>),
+    commands: [&str; 4],
+) -> Result<String, String> {
+    let mut path = Vec::new();
+    let mut current: Option<(f64, f64)> = None;
+    for curve in curves {
+        let points = curve.control_points();
+        let degree = curve.degree();
+        if degree == 0 || degree > 3 || commands[degree].is_empty() {
+            return Err(format!(
+                "Curves of degree {} can't be written as a path",
+                degree
+            ));
+        }
+        if current != Some(points[0]) {
+            write(commands[0], &points[..1], &mut path);
+        }
+        write(commands[degree], &points[1..], &mut path);
+        current = points.last().copied();
+    }
+    Ok(path.join(" "))
+}
+
+impl Interpolator {
+    /// Converts the segment j of a polynomial interpolant into the Bézier curve (x, f(x)) over [x_j, x_j+1].
+    /// Linear segments give lines, quadratic ones quadratic curves and the cubic splines cubic curves.
+    pub fn bezier_segment(&self, j: usize) -> Result<BezierCurve, String> {
+        if j + 1 >= self.x_values.len() {
+            return Err(format!("Segment {} is out of bounds", j));
+        }
+        if self.spans_gap(j) {
+            return Err(format!("Segment {} lies across a gap", j));
+        }
+        let degree = match self.interpolation_type {
+            InterpolationType::Linear => 1,
+            InterpolationType::Quadratic | InterpolationType::ConvexityPreserving => 2,
+            InterpolationType::Cubic
+            | InterpolationType::Cardinal { .. }
+            | InterpolationType::MonotoneCubic
+            | InterpolationType::Bounded { .. } => 3,
+            _ => {
+                return Err(format!(
+                    "{} interpolation has no Bézier representation",
+                    self.interpolation_type
+                ))
+            }
+        };
+
+        // Power basis in t = (x - x_j) / h, then the usual change to the Bernstein basis
+        let (x0, h) = (self.x_values[j], self.x_values[j + 1] - self.x_values[j]);
+        let (a0, a1, a2, a3) = (
+            self.y_values[j],
+            self.b_coeffs[j] * h,
+            self.c_coeffs[j] * h * h,
+            self.d_coeffs[j] * h * h * h,
+        );
+        let mut y = match degree {
+            1 => vec![a0, a0 + a1],
+            2 => vec![a0, a0 + a1 / 2.0, a0 + a1 + a2],
+            _ => vec![
+                a0,
+                a0 + a1 / 3.0,
+                a0 + 2.0 * a1 / 3.0 + a2 / 3.0,
+                a0 + a1 + a2 + a3,
+            ],
+        };
+        // Ending exactly on the next knot keeps the path connected, the quadratic spline is the only one
+        // that doesn't pass through it
+        if self.interpolation_type != InterpolationType::Quadratic {
+            y[degree] = self.y_values[j + 1];
+        }
+        let control_points = y
+            .into_iter()
+            .enumerate()
+            .map(|(i, y)| (x0 + h * i as f64 / degree as f64, y))
+            .collect();
+        Ok(BezierCurve { control_points })
+    }
+
+    /// Converts every segment of the interpolant into a Bézier curve, see `bezier_segment`.
+    /// Segments across kept gaps are left out, so a path through the curves is broken at the gaps.
+    pub fn to_bezier(&self) -> Result<Vec<BezierCurve>, String> {
+        (0..self.x_values.len() - 1)
+            .filter(|&j| !self.spans_gap(j))
+            .map(|j| self.bezier_segment(j))
+            .collect()
+    }
+}
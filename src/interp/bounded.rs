@@ -0,0 +1,35 @@
+/// Limits the slopes of a cubic Hermite spline so that every segment stays within [lower, upper].
+/// The Bernstein coefficients of a segment are y_j, y_j + h * m_j / 3, y_j+1 - h * m_j+1 / 3 and y_j+1, and the cubic
+/// lies within their range, so it's enough to keep the two inner ones within the bounds. Each slope is clamped
+/// on both of its segments, zero always fits as the values are within the bounds, and the spline stays C1.
+pub(super) fn limit_slopes(dx: &[f64], y: &[f64], m: &mut [f64], lower: f64, upper: f64) {
+    let n = dx.len();
+    for i in 0..=n {
+        let (mut low, mut high) = (f64::NEG_INFINITY, f64::INFINITY);
+        if i < n {
+            // Segment on the right, y_i + h * m_i / 3 within the bounds
+            low = low.max(3.0 * (lower - y[i]) / dx[i]);
+            high = high.min(3.0 * (upper - y[i]) / dx[i]);
+        }
+        if i > 0 {
+            // Segment on the left, y_i - h * m_i / 3 within the bounds
+            low = low.max(3.0 * (y[i] - upper) / dx[i - 1]);
+            high = high.min(3.0 * (y[i] - lower) / dx[i - 1]);
+        }
+        m[i] = m[i].clamp(low, high);
+    }
+}
+
+/// Slope of the line extending the spline beyond a boundary knot, outward being -1 on the left and 1 on the right.
+/// The boundary slope is kept when the line heads towards an infinite bound, otherwise the line would eventually
+/// cross a bound and the boundary value is held instead.
+pub(super) fn extension_slope(slope: f64, outward: f64, lower: f64, upper: f64) -> f64 {
+    let direction = slope * outward;
+    if (direction > 0.0 && upper == f64::INFINITY)
+        || (direction < 0.0 && lower == f64::NEG_INFINITY)
+    {
+        slope
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,309 @@
+use super::normalize::{normalize, DuplicatePolicy, NormalizationReport};
+use super::{ExtrapolationStrategy, InterpolationType, Interpolator};
+use std::ops::Range;
+
+/// Enum to define how the missing values (NaN) of the data are handled
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GapPolicy {
+    Drop, // Remove the missing nodes and interpolate across the gaps
+    Fill, // Fill the missing nodes with the values interpolated from the others and keep them as nodes
+    Keep, // Keep the gaps, evaluation inside a gap returns NaN
+}
+
+/// Builder pattern for Interpolator configuration.
+pub struct InterpolatorBuilder {
+    interpolation_type: InterpolationType,
+    extrap_strategy: Option<ExtrapolationStrategy>,
+    data: Option<(Vec<f64>, Vec<f64>)>,
+    gap_policy: Option<GapPolicy>,
+    max_gap: Option<f64>,
+    duplicate_policy: Option<DuplicatePolicy>,
+}
+
+/// Run of consecutive missing nodes, given by the indices of the valid nodes around it
+struct MissingRun {
+    before: Option<usize>,
+    after: Option<usize>,
+    missing: Vec<usize>,
+}
+
+impl InterpolatorBuilder {
+    /// Creates a new instance of `InterpolatorBuilder`.
+    pub fn new(interpolation_type: InterpolationType) -> Self {
+        Self {
+            interpolation_type,
+            extrap_strategy: None,
+            data: None,
+            gap_policy: None,
+            max_gap: None,
+            duplicate_policy: None,
+        }
+    }
+
+    /// Sets the points to interpolate, missing values are marked with NaN.
+    pub fn data(mut self, x_values: Vec<f64>, y_values: Vec<f64>) -> Self {
+        self.data = Some((x_values, y_values));
+        self
+    }
+
+    /// Sets the extrapolation strategy, no extrapolation by default.
+    pub fn extrapolation(mut self, extrap_strategy: ExtrapolationStrategy) -> Self {
+        self.extrap_strategy = Some(extrap_strategy);
+        self
+    }
+
+    /// Sets how the missing values are handled, missing values are an error by default.
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.gap_policy = Some(gap_policy);
+        self
+    }
+
+    /// Sets the longest gap that is bridged by dropping or filling the missing values, longer gaps are kept.
+    /// The length of a gap is the distance between the valid points around it, or between the last valid point
+    /// and the farthest missing one at the ends of the data.
+    pub fn max_gap(mut self, max_gap: f64) -> Self {
+        self.max_gap = Some(max_gap);
+        self
+    }
+
+    /// Enables sorting the points by x and merging the points sharing the same x with the given policy.
+    /// Without it the x values must be strictly increasing.
+    pub fn normalize(mut self, duplicate_policy: DuplicatePolicy) -> Self {
+        self.duplicate_policy = Some(duplicate_policy);
+        self
+    }
+
+    /// Builds and returns the `Interpolator` instance.
+    pub fn build(self) -> Result<Interpolator, String> {
+        self.build_with_report()
+            .map(|(interpolator, _)| interpolator)
+    }
+
+    /// Builds the `Interpolator` instance and returns it with the report of the changes made to the data.
+    pub fn build_with_report(self) -> Result<(Interpolator, NormalizationReport), String> {
+        let (x_values, y_values) = self.data.ok_or("Data must be specified.")?;
+        if x_values.len() != y_values.len() {
+            return Err(String::from(
+                "x_values and y_values must have the same length.",
+            ));
+        }
+        if x_values.iter().any(|x| x.is_nan()) {
+            return Err(String::from("x_values must not contain NaN."));
+        }
+        let (x_values, y_values, report) = match self.duplicate_policy {
+            Some(duplicate_policy) => normalize(&x_values, &y_values, duplicate_policy)?,
+            // Normalizing strictly increasing data changes nothing
+            None => normalize(&x_values, &y_values, DuplicatePolicy::Error)
+                .ok()
+                .filter(|(_, _, report)| !report.reordered)
+                .ok_or(
+                    "x_values must be strictly increasing, normalization can sort and merge them.",
+                )?,
+        };
+        let extrap_strategy = self.extrap_strategy.unwrap_or(ExtrapolationStrategy::None);
+        if let Some(max_gap) = self.max_gap {
+            if max_gap.is_nan() || max_gap < 0.0 {
+                return Err(String::from("Max gap must be non-negative."));
+            }
+        }
+
+        let runs = missing_runs(&y_values);
+        let gap_policy = match (self.gap_policy, runs.is_empty()) {
+            (Some(gap_policy), _) => gap_policy,
+            (None, true) => GapPolicy::Drop,
+            (None, false) => {
+                return Err(String::from(
+                    "y_values contain NaN, a gap policy must be specified.",
+                ))
+            }
+        };
+
+        // Runs that are kept as hard gaps and runs that are bridged
+        let (kept, bridged): (Vec<MissingRun>, Vec<MissingRun>) =
+            runs.into_iter().partition(|run| {
+                gap_policy == GapPolicy::Keep
+                    || self
+                        .max_gap
+                        .is_some_and(|max_gap| run.length(&x_values) > max_gap)
+            });
+        let gaps: Vec<(f64, f64)> = kept
+            .iter()
+            .map(|run| {
+                (
+                    run.before.map_or(f64::NEG_INFINITY, |i| x_values[i]),
+                    run.after.map_or(f64::INFINITY, |i| x_values[i]),
+                )
+            })
+            .collect();
+
+        let valid: Vec<usize> = (0..x_values.len())
+            .filter(|&i| !y_values[i].is_nan())
+            .collect();
+        if valid.len() < 2 {
+            return Err(String::from(
+                "At least two points with a value are required.",
+            ));
+        }
+        let mut interpolator = fit_pieces(
+            valid.iter().map(|&i| x_values[i]).collect(),
+            valid.iter().map(|&i| y_values[i]).collect(),
+            self.interpolation_type,
+            extrap_strategy,
+            gaps.clone(),
+        )?;
+
+        if gap_policy == GapPolicy::Fill && !bridged.is_empty() {
+            // Missing nodes outside of the data can only be filled by extrapolation
+            let (x_first, x_last) = (x_values[valid[0]], x_values[valid[valid.len() - 1]]);
+            let fillable = |i: &usize| {
+                extrap_strategy != ExtrapolationStrategy::None
+                    || (x_first..=x_last).contains(&x_values[*i])
+            };
+            let filled: Vec<(usize, f64)> = bridged
+                .iter()
+                .flat_map(|run| run.missing.iter().copied())
+                .filter(fillable)
+                .map(|i| (i, interpolator.interpolate(x_values[i])))
+                .collect();
+            let mut y_filled = y_values.clone();
+            for (i, y) in filled {
+                y_filled[i] = y;
+            }
+            let nodes: Vec<usize> = (0..x_values.len())
+                .filter(|&i| !y_filled[i].is_nan())
+                .collect();
+            interpolator = fit_pieces(
+                nodes.iter().map(|&i| x_values[i]).collect(),
+                nodes.iter().map(|&i| y_filled[i]).collect(),
+                self.interpolation_type,
+                extrap_strategy,
+                gaps,
+            )?;
+        }
+        Ok((interpolator, report))
+    }
+}
+
+/// Fits the interpolant separately on each run of points between the kept gaps, so that no fit spans a gap.
+/// The pieces are joined by straight segments across the gaps, where the interpolated value is NaN anyway.
+fn fit_pieces(
+    x_values: Vec<f64>,
+    y_values: Vec<f64>,
+    interpolation_type: InterpolationType,
+    extrap_strategy: ExtrapolationStrategy,
+    gaps: Vec<(f64, f64)>,
+) -> Result<Interpolator, String> {
+    interpolation_type.validate(&x_values, &y_values)?;
+    let pieces = split_at_gaps(&x_values, &gaps);
+    if pieces.len() == 1 {
+        let mut interpolator =
+            Interpolator::new(x_values, y_values, interpolation_type, extrap_strategy);
+        interpolator.gaps = gaps;
+        return Ok(interpolator);
+    }
+
+    // The spline under tension keeps one second derivative per knot, the steps and sinc have no coefficients
+    let per_knot = matches!(interpolation_type, InterpolationType::Tension(_));
+    let per_segment = !per_knot
+        && !matches!(
+            interpolation_type,
+            InterpolationType::ConstantBackward
+                | InterpolationType::ConstantForward
+                | InterpolationType::Sinc(_)
+        );
+    let mut joined = Interpolator {
+        x_values: vec![],
+        y_values: vec![],
+        b_coeffs: vec![],
+        c_coeffs: vec![],
+        d_coeffs: vec![],
+        interpolation_type,
+        extrap_strategy,
+        gaps,
+    };
+    for piece in pieces {
+        if let (Some(&x_end), Some(&y_end), true) =
+            (joined.x_values.last(), joined.y_values.last(), per_segment)
+        {
+            let h = x_values[piece.start] - x_end;
+            joined.b_coeffs.push((y_values[piece.start] - y_end) / h);
+            joined.c_coeffs.push(0.0);
+            joined.d_coeffs.push(0.0);
+        }
+        if piece.len() == 1 {
+            joined.x_values.push(x_values[piece.start]);
+            joined.y_values.push(y_values[piece.start]);
+            if per_knot {
+                joined.c_coeffs.push(0.0);
+            }
+            continue;
+        }
+        let fitted = Interpolator::new(
+            x_values[piece.clone()].to_vec(),
+            y_values[piece].to_vec(),
+            interpolation_type,
+            extrap_strategy,
+        );
+        let segments = fitted.x_values.len() - 1;
+        if per_knot {
+            joined.c_coeffs.extend(&fitted.c_coeffs);
+        } else if per_segment {
+            joined.b_coeffs.extend(&fitted.b_coeffs[..segments]);
+            joined.c_coeffs.extend(&fitted.c_coeffs[..segments]);
+            joined.d_coeffs.extend(&fitted.d_coeffs[..segments]);
+        }
+        joined.x_values.extend(fitted.x_values);
+        joined.y_values.extend(fitted.y_values);
+    }
+    Ok(joined)
+}
+
+/// Splits the knots into the runs of consecutive knots that are not separated by a gap
+pub(super) fn split_at_gaps(x_values: &[f64], gaps: &[(f64, f64)]) -> Vec<Range<usize>> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    for i in 0..x_values.len() - 1 {
+        if gaps
+            .iter()
+            .any(|&(gap_start, gap_end)| gap_start <= x_values[i] && x_values[i + 1] <= gap_end)
+        {
+            pieces.push(start..i + 1);
+            start = i + 1;
+        }
+    }
+    pieces.push(start..x_values.len());
+    pieces
+}
+
+impl MissingRun {
+    fn length(&self, x_values: &[f64]) -> f64 {
+        let first = self.missing[0];
+        let last = self.missing[self.missing.len() - 1];
+        let start = x_values[self.before.unwrap_or(first)];
+        let end = x_values[self.after.unwrap_or(last)];
+        end - start
+    }
+}
+
+/// Splits the missing values into runs of consecutive nodes
+fn missing_runs(y_values: &[f64]) -> Vec<MissingRun> {
+    let mut runs: Vec<MissingRun> = Vec::new();
+    for (i, y) in y_values.iter().enumerate() {
+        if !y.is_nan() {
+            continue;
+        }
+        match runs.last_mut() {
+            Some(run) if run.missing.last() == Some(&(i - 1)) => run.missing.push(i),
+            _ => runs.push(MissingRun {
+                before: i.checked_sub(1),
+                after: None,
+                missing: vec![i],
+            }),
+        }
+    }
+    for run in &mut runs {
+        let next = run.missing[run.missing.len() - 1] + 1;
+        run.after = (next < y_values.len()).then_some(next);
+    }
+    runs
+}
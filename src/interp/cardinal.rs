@@ -0,0 +1,35 @@
+use super::CatmullRomParameterization;
+
+/// Computes the slopes of a cardinal spline at every knot.
+/// The slope is taken from the tangent of the Catmull-Rom curve through the neighbouring points,
+/// with the knot spacing given by the parameterization, and scaled by (1 - tension).
+pub(super) fn cardinal_slopes(
+    x: &[f64],
+    y: &[f64],
+    tension: f64,
+    parameterization: &CatmullRomParameterization,
+) -> Vec<f64> {
+    let n = x.len() - 1; // Number of segments
+    let alpha = match parameterization {
+        CatmullRomParameterization::Uniform => 0.0,
+        CatmullRomParameterization::Centripetal => 0.5,
+        CatmullRomParameterization::Chordal => 1.0,
+    };
+    let spacing: Vec<f64> = (0..n)
+        .map(|i| (x[i + 1] - x[i]).hypot(y[i + 1] - y[i]).powf(alpha))
+        .collect();
+
+    let mut m = vec![0.0; n + 1];
+    // One-sided differences at the end points
+    m[0] = (y[1] - y[0]) / (x[1] - x[0]);
+    m[n] = (y[n] - y[n - 1]) / (x[n] - x[n - 1]);
+    for i in 1..n {
+        let (d0, d1) = (spacing[i - 1], spacing[i]);
+        // Tangent of the non-uniform Catmull-Rom curve at point i with respect to its parameter
+        let tangent = |v: &[f64]| {
+            (v[i] - v[i - 1]) / d0 - (v[i + 1] - v[i - 1]) / (d0 + d1) + (v[i + 1] - v[i]) / d1
+        };
+        m[i] = tangent(y) / tangent(x);
+    }
+    m.iter().map(|slope| (1.0 - tension) * slope).collect()
+}
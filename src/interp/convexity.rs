@@ -0,0 +1,62 @@
+/// Knots and polynomial coefficients of a piecewise polynomial
+pub(super) type PiecewisePolynomial = (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>);
+
+/// Builds the C1 shape-preserving quadratic spline of Schumaker.
+/// Every segment gets an extra knot where needed, so the derivative is piecewise linear between the slopes
+/// at the data points. The slopes lie between the neighbouring secants, which makes the spline convex
+/// (or concave) wherever the data is.
+pub(super) fn schumaker_spline(x: &[f64], y: &[f64]) -> PiecewisePolynomial {
+    let n = x.len() - 1; // Number of segments
+    let h: Vec<f64> = (0..n).map(|i| x[i + 1] - x[i]).collect();
+    let secants: Vec<f64> = (0..n).map(|i| (y[i + 1] - y[i]) / h[i]).collect();
+
+    // Slope of the parabola through three consecutive points, it always lies between the two secants
+    let mut m = vec![secants[0]; n + 1];
+    for i in 1..n {
+        m[i] = (h[i] * secants[i - 1] + h[i - 1] * secants[i]) / (h[i - 1] + h[i]);
+    }
+    if n > 1 {
+        m[0] = (3.0 * secants[0] - m[1]) / 2.0;
+        m[n] = (3.0 * secants[n - 1] - m[n - 1]) / 2.0;
+    } else {
+        m[n] = secants[0];
+    }
+
+    let mut knots = Vec::with_capacity(2 * n + 1);
+    let mut values = Vec::with_capacity(2 * n + 1);
+    let mut b = Vec::with_capacity(2 * n);
+    let mut c = Vec::with_capacity(2 * n);
+    for j in 0..n {
+        let (m0, m1, s) = (m[j], m[j + 1], secants[j]);
+        knots.push(x[j]);
+        values.push(y[j]);
+        if (m0 + m1 - 2.0 * s).abs() <= f64::EPSILON * (m0.abs() + m1.abs() + s.abs()) {
+            // A single parabola matches both slopes
+            b.push(m0);
+            c.push((m1 - m0) / (2.0 * h[j]));
+            continue;
+        }
+
+        // Width of the first piece, the second slope follows from matching the secant
+        let alpha = if (m0 - s) * (m1 - s) >= 0.0 {
+            h[j] / 2.0
+        } else if (m1 - s).abs() < (m0 - s).abs() {
+            h[j] * (m1 - s) / (m1 - m0)
+        } else {
+            h[j] - h[j] * (s - m0) / (m1 - m0)
+        };
+        let beta = h[j] - alpha;
+        let m_bar = (2.0 * s * h[j] - m0 * alpha - m1 * beta) / h[j];
+
+        b.push(m0);
+        c.push((m_bar - m0) / (2.0 * alpha));
+        knots.push(x[j] + alpha);
+        values.push(y[j] + (m0 + m_bar) * alpha / 2.0);
+        b.push(m_bar);
+        c.push((m1 - m_bar) / (2.0 * beta));
+    }
+    knots.push(x[n]);
+    values.push(y[n]);
+    let d = vec![0.0; b.len()];
+    (knots, values, b, c, d)
+}
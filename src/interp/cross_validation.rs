@@ -0,0 +1,151 @@
+use super::{CatmullRomParameterization, ExtrapolationStrategy, InterpolationType, Interpolator};
+
+/// Enum to define how the points are split between fitting and validation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValidationMethod {
+    LeaveOneOut,  // Each point is predicted from all the others
+    KFold(usize), // Point i goes to fold i mod k, each fold is predicted from the other folds
+}
+
+/// Out-of-sample errors of an interpolation scheme
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemeScore {
+    pub interpolation_type: InterpolationType,
+    pub extrap_strategy: ExtrapolationStrategy,
+    pub rmse: f64,
+    pub max_error: f64,
+    pub evaluated: usize, // Number of points predicted
+    pub skipped: usize, // Number of points the scheme couldn't predict, e.g. out of bounds without extrapolation
+}
+
+/// Scores of the schemes ranked from the best, see `cross_validate`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossValidationReport {
+    pub scores: Vec<SchemeScore>,
+}
+
+impl CrossValidationReport {
+    /// Returns the best scheme
+    pub fn winner(&self) -> &SchemeScore {
+        &self.scores[0]
+    }
+}
+
+/// Returns the parameter-free interpolation types, along with the centripetal Catmull-Rom spline,
+/// combined with constant and spline extrapolation
+pub fn standard_schemes() -> Vec<(InterpolationType, ExtrapolationStrategy)> {
+    let types = [
+        InterpolationType::Linear,
+        InterpolationType::Quadratic,
+        InterpolationType::Cubic,
+        InterpolationType::ConstantBackward,
+        InterpolationType::ConstantForward,
+        InterpolationType::Cardinal {
+            tension: 0.0,
+            parameterization: CatmullRomParameterization::Centripetal,
+        },
+        InterpolationType::ConvexityPreserving,
+        InterpolationType::MonotoneCubic,
+    ];
+    types
+        .iter()
+        .flat_map(|&interpolation_type| {
+            [
+                ExtrapolationStrategy::Constant,
+                ExtrapolationStrategy::ExtendSpline,
+            ]
+            .map(|extrap_strategy| (interpolation_type, extrap_strategy))
+        })
+        .collect()
+}
+
+/// Predicts the held out points with every scheme and reports the root mean square and the maximum errors.
+/// The schemes are ranked by the number of points they couldn't predict and then by their RMSE,
+/// so a scheme without extrapolation doesn't win by skipping the points at the ends.
+pub fn cross_validate(
+    x_values: &[f64],
+    y_values: &[f64],
+    schemes: &[(InterpolationType, ExtrapolationStrategy)],
+    method: ValidationMethod,
+) -> Result<CrossValidationReport, String> {
+    let n = x_values.len();
+    if y_values.len() != n || n < 3 {
+        return Err(String::from(
+            "x_values and y_values must have the same length and contain at least three points",
+        ));
+    }
+    if x_values.iter().chain(y_values).any(|v| !v.is_finite()) {
+        return Err(String::from("x_values and y_values must be finite"));
+    }
+    if x_values.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(String::from("x_values must be strictly increasing"));
+    }
+    if schemes.is_empty() {
+        return Err(String::from("At least one scheme is required"));
+    }
+    if schemes
+        .iter()
+        .any(|(kind, _)| matches!(kind, InterpolationType::Sinc(_)))
+    {
+        return Err(String::from(
+            "Sinc interpolation requires equally spaced knots, it can't be fitted to a subset of them",
+        ));
+    }
+    for (kind, _) in schemes {
+        kind.validate(x_values, y_values)?;
+    }
+    let folds = match method {
+        ValidationMethod::LeaveOneOut => n,
+        ValidationMethod::KFold(k) if (2..=n).contains(&k) => k,
+        ValidationMethod::KFold(k) => {
+            return Err(format!(
+                "The number of folds must be between 2 and the number of points {}, got {}",
+                n, k
+            ))
+        }
+    };
+
+    let mut scores: Vec<SchemeScore> = schemes
+        .iter()
+        .map(|&(interpolation_type, extrap_strategy)| {
+            let mut squared_sum = 0.0;
+            let mut max_error: f64 = 0.0;
+            let mut evaluated = 0;
+            for fold in 0..folds {
+                let (held_out, kept): (Vec<usize>, Vec<usize>) =
+                    (0..n).partition(|i| i % folds == fold);
+                if kept.len() < 2 {
+                    continue;
+                }
+                let interpolator = Interpolator::new(
+                    kept.iter().map(|&i| x_values[i]).collect(),
+                    kept.iter().map(|&i| y_values[i]).collect(),
+                    interpolation_type,
+                    extrap_strategy,
+                );
+                for i in held_out {
+                    if let Ok(prediction) = interpolator.try_interpolate(x_values[i]) {
+                        let error = (prediction - y_values[i]).abs();
+                        squared_sum += error * error;
+                        max_error = max_error.max(error);
+                        evaluated += 1;
+                    }
+                }
+            }
+            SchemeScore {
+                interpolation_type,
+                extrap_strategy,
+                rmse: if evaluated > 0 {
+                    (squared_sum / evaluated as f64).sqrt()
+                } else {
+                    f64::NAN
+                },
+                max_error,
+                evaluated,
+                skipped: n - evaluated,
+            }
+        })
+        .collect();
+    scores.sort_by(|a, b| a.skipped.cmp(&b.skipped).then(a.rmse.total_cmp(&b.rmse)));
+    Ok(CrossValidationReport { scores })
+}
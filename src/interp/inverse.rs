@@ -0,0 +1,278 @@
+use super::tension::sinh_ratio;
+use super::{ExtrapolationStrategy, InterpolationType, Interpolator};
+
+impl Interpolator {
+    /// Finds x such that the interpolated value equals `y`.
+    /// The interpolant has to be monotone over the data range, otherwise an error is returned.
+    pub fn inverse(&self, y: f64) -> Result<f64, String> {
+        let increasing = self.check_monotone()?;
+        let (y_first, y_last) = (
+            *self.y_values.first().unwrap(),
+            *self.y_values.last().unwrap(),
+        );
+        let (y_min, y_max) = if increasing {
+            (y_first, y_last)
+        } else {
+            (y_last, y_first)
+        };
+        if !(y_min..=y_max).contains(&y) {
+            return Err(format!(
+                "Value y = {} is outside of the interpolated range [{}, {}].",
+                y, y_min, y_max
+            ));
+        }
+
+        // Each segment is monotone, so the first segment bracketing y contains the solution
+        let j = (0..self.x_values.len() - 1)
+            .find(|&j| {
+                let (lo, hi) = if increasing {
+                    (self.y_values[j], self.y_values[j + 1])
+                } else {
+                    (self.y_values[j + 1], self.y_values[j])
+                };
+                lo <= y && y <= hi
+            })
+            .unwrap();
+        self.solve_segment(j, y)
+    }
+
+    /// Builds an interpolator of the inverse function by sampling the inverse on every segment.
+    /// This is useful when the inverse has to be evaluated many times, e.g. for sampling.
+    pub fn inverse_interpolator(
+        &self,
+        samples_per_segment: usize,
+        interpolation_type: InterpolationType,
+        extrap_strategy: ExtrapolationStrategy,
+    ) -> Result<Interpolator, String> {
+        if samples_per_segment == 0 {
+            return Err(String::from("At least one sample per segment is required"));
+        }
+        let increasing = self.check_monotone()?;
+        if self.y_values.windows(2).any(|w| w[0] == w[1]) {
+            return Err(String::from(
+                "Inverse interpolator requires a strictly monotone interpolant",
+            ));
+        }
+
+        let n = self.x_values.len() - 1;
+        let mut x_values = Vec::with_capacity(n * samples_per_segment + 1);
+        let mut y_values = Vec::with_capacity(n * samples_per_segment + 1);
+        for j in 0..n {
+            let h = self.x_values[j + 1] - self.x_values[j];
+            for k in 0..samples_per_segment {
+                let x = self.x_values[j] + h * k as f64 / samples_per_segment as f64;
+                x_values.push(x);
+                y_values.push(self.interpolate(x));
+            }
+        }
+        x_values.push(self.x_values[n]);
+        y_values.push(self.y_values[n]);
+
+        if !increasing {
+            x_values.reverse();
+            y_values.reverse();
+        }
+        // The sampled values become the knots of the inverse
+        interpolation_type.validate(&y_values, &x_values)?;
+        Ok(Interpolator::new(
+            y_values,
+            x_values,
+            interpolation_type,
+            extrap_strategy,
+        ))
+    }
+
+    /// Checks that the interpolant is monotone, returns true if it is increasing
+    fn check_monotone(&self) -> Result<bool, String> {
+        if !self.gaps.is_empty() {
+            return Err(String::from("Inverse is not defined for data with gaps"));
+        }
+        if matches!(
+            self.interpolation_type,
+            InterpolationType::ConstantForward
+                | InterpolationType::ConstantBackward
+                | InterpolationType::Sinc(_)
+        ) {
+            return Err(format!(
+                "Inverse is not defined for {:?} interpolation.",
+                self.interpolation_type
+            ));
+        }
+        let increasing = self.y_values.last().unwrap() > self.y_values.first().unwrap();
+        let sign = if increasing { 1.0 } else { -1.0 };
+        if self.y_values.windows(2).any(|w| sign * (w[1] - w[0]) < 0.0) {
+            return Err(String::from(
+                "Data is not monotone, the inverse is not defined",
+            ));
+        }
+        if self.y_values.first() == self.y_values.last() {
+            return Err(String::from("Data is constant, the inverse is not defined"));
+        }
+
+        for j in 0..self.x_values.len() - 1 {
+            let monotone = match self.interpolation_type {
+                InterpolationType::Tension(sigma) => {
+                    self.is_tension_segment_monotone(j, sigma, sign)
+                }
+                _ => self.is_polynomial_segment_monotone(j, sign),
+            };
+            if !monotone {
+                return Err(format!(
+                    "Interpolant is not monotone on the segment [{}, {}]",
+                    self.x_values[j],
+                    self.x_values[j + 1]
+                ));
+            }
+        }
+        Ok(increasing)
+    }
+
+    /// Checks that the derivative b + 2c*dx + 3d*dx^2 keeps the given sign on segment j
+    fn is_polynomial_segment_monotone(&self, j: usize, sign: f64) -> bool {
+        let h = self.x_values[j + 1] - self.x_values[j];
+        let (b, c, d) = (self.b_coeffs[j], self.c_coeffs[j], self.d_coeffs[j]);
+        let derivative = |dx: f64| b + 2.0 * c * dx + 3.0 * d * dx * dx;
+        let mut candidates = vec![0.0, h];
+        if d != 0.0 {
+            let vertex = -c / (3.0 * d);
+            if 0.0 < vertex && vertex < h {
+                candidates.push(vertex);
+            }
+        }
+        let tolerance = 1e-12 * (b.abs() + (2.0 * c * h).abs() + (3.0 * d * h * h).abs());
+        candidates
+            .into_iter()
+            .all(|dx| sign * derivative(dx) >= -tolerance)
+    }
+
+    /// Checks that the derivative of the spline under tension keeps the given sign on segment j.
+    /// The second derivative z_j * sinh(sigma * (1 - t)) / sinh(sigma) + z_j+1 * sinh(sigma * t) / sinh(sigma)
+    /// vanishes at most once, so the derivative only has to be checked there and at the ends.
+    fn is_tension_segment_monotone(&self, j: usize, sigma: f64, sign: f64) -> bool {
+        let (x0, x1) = (self.x_values[j], self.x_values[j + 1]);
+        let (a, b) = (self.c_coeffs[j], self.c_coeffs[j + 1]);
+        let mut candidates = vec![x0, x1];
+        if a * b < 0.0 {
+            let curvature = |t: f64| a * sinh_ratio(sigma, 1.0 - t) + b * sinh_ratio(sigma, t);
+            let (mut lo, mut hi) = (0.0, 1.0);
+            for _ in 0..60 {
+                let mid = (lo + hi) / 2.0;
+                if curvature(mid).signum() == a.signum() {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            candidates.push(x0 + (lo + hi) / 2.0 * (x1 - x0));
+        }
+        let scale = (self.y_values[j + 1] - self.y_values[j]).abs() / (x1 - x0)
+            + (a.abs() + b.abs()) * (x1 - x0);
+        candidates
+            .into_iter()
+            .all(|x| sign * self.tension_derivative(j, x, sigma) >= -1e-12 * scale)
+    }
+
+    /// Solves f(t) = 0 for the offset t into the monotone segment j, the bracket [0, h] always holds.
+    /// The start point interpolates r linearly.
+    fn solve_on_segment(
+        &self,
+        j: usize,
+        f: impl Fn(f64) -> f64,
+        df: impl Fn(f64) -> f64,
+        r: f64,
+    ) -> f64 {
+        let h = self.x_values[j + 1] - self.x_values[j];
+        let sign = (self.y_values[j + 1] - self.y_values[j]).signum();
+        let start = r / (self.y_values[j + 1] - self.y_values[j]) * h;
+        newton_bisection(
+            |t| sign * f(t),
+            |t| sign * df(t),
+            (0.0, h),
+            start,
+            1e-15 * h,
+        )
+        .clamp(0.0, h)
+    }
+
+    /// Solves f(x) = y on segment j using the solver that matches the degree of the segment
+    pub(super) fn solve_segment(&self, j: usize, y: f64) -> Result<f64, String> {
+        let x0 = self.x_values[j];
+        let h = self.x_values[j + 1] - x0;
+        if y == self.y_values[j] {
+            return Ok(x0);
+        }
+        if y == self.y_values[j + 1] {
+            return Ok(self.x_values[j + 1]);
+        }
+
+        let r = y - self.y_values[j];
+        if let InterpolationType::Tension(sigma) = self.interpolation_type {
+            let dx = self.solve_on_segment(
+                j,
+                |t| self.evaluate_tension(j, x0 + t, sigma) - y,
+                |t| self.tension_derivative(j, x0 + t, sigma),
+                r,
+            );
+            return Ok(x0 + dx);
+        }
+        let (b, c, d) = (self.b_coeffs[j], self.c_coeffs[j], self.d_coeffs[j]);
+        let dx = if d != 0.0 {
+            // Cubic segment, the closed form is badly conditioned so we are using Newton's method
+            self.solve_on_segment(
+                j,
+                |t| b * t + c * t * t + d * t * t * t - r,
+                |t| b + 2.0 * c * t + 3.0 * d * t * t,
+                r,
+            )
+        } else if c != 0.0 {
+            // Quadratic segment, using the numerically stable form of the quadratic formula
+            let discriminant = (b * b + 4.0 * c * r).max(0.0);
+            let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+            let roots = [q / c, if q != 0.0 { -r / q } else { f64::NAN }];
+            roots
+                .into_iter()
+                .filter(|t| t.is_finite())
+                .min_by(|t1, t2| distance(*t1, h).total_cmp(&distance(*t2, h)))
+                .unwrap()
+        } else {
+            r / b
+        };
+        Ok(x0 + dx.clamp(0.0, h))
+    }
+}
+
+/// Solves f(t) = 0 for an increasing f with Newton's method safeguarded by bisection, the root must lie
+/// in the bracket (lo, hi) and the iteration starts from t. Stops when the step or the bracket falls below the tolerance.
+pub(super) fn newton_bisection(
+    f: impl Fn(f64) -> f64,
+    df: impl Fn(f64) -> f64,
+    (mut lo, mut hi): (f64, f64),
+    mut t: f64,
+    tolerance: f64,
+) -> f64 {
+    for _ in 0..100 {
+        let value = f(t);
+        if value < 0.0 {
+            lo = t;
+        } else {
+            hi = t;
+        }
+        let slope = df(t);
+        let newton = t - value / slope;
+        let next = if slope != 0.0 && lo < newton && newton < hi {
+            newton
+        } else {
+            (lo + hi) / 2.0
+        };
+        if (next - t).abs() <= tolerance || hi - lo <= tolerance {
+            return next;
+        }
+        t = next;
+    }
+    t
+}
+
+/// Distance from a point to the interval [0, h]
+fn distance(t: f64, h: f64) -> f64 {
+    (-t).max(t - h).max(0.0)
+}
@@ -0,0 +1,134 @@
+use super::{ExtrapolationStrategy, InterpolationType, Interpolator};
+use std::marker::PhantomData;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Maps the keys of a `KeyedInterpolator`, e.g. dates or timestamps, to the f64 abscissae of the interpolant.
+/// Any closure `Fn(&K) -> f64` is an abscissa, which covers custom date types and day-count conventions.
+pub trait Abscissa<K> {
+    /// Converts the key to the abscissa, the conversion must be increasing
+    fn to_f64(&self, key: &K) -> f64;
+}
+
+impl<K, F: Fn(&K) -> f64> Abscissa<K> for F {
+    #[inline]
+    fn to_f64(&self, key: &K) -> f64 {
+        self(key)
+    }
+}
+
+/// Abscissa of plain f64 keys, used as they are
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Identity;
+
+impl Abscissa<f64> for Identity {
+    #[inline]
+    fn to_f64(&self, key: &f64) -> f64 {
+        *key
+    }
+}
+
+/// Abscissa measuring the time elapsed since an origin in years of the given number of days, e.g. 365 for ACT/365.
+/// It converts `SystemTime` and `i64` Unix timestamps in seconds.
+#[derive(Debug, Clone, Copy)]
+pub struct YearFraction<K> {
+    origin: K,
+    days_per_year: f64,
+}
+
+impl<K> YearFraction<K> {
+    /// Creates the conversion from the origin and the number of days per year
+    pub fn new(origin: K, days_per_year: f64) -> Self {
+        if !(days_per_year > 0.0 && days_per_year.is_finite()) {
+            panic!(
+                "Days per year must be positive and finite, got {}.",
+                days_per_year
+            );
+        }
+        Self {
+            origin,
+            days_per_year,
+        }
+    }
+}
+
+impl Abscissa<i64> for YearFraction<i64> {
+    #[inline]
+    fn to_f64(&self, key: &i64) -> f64 {
+        // The difference of two i64 can overflow, it's exact in i128
+        (*key as i128 - self.origin as i128) as f64 / (SECONDS_PER_DAY * self.days_per_year)
+    }
+}
+
+impl Abscissa<SystemTime> for YearFraction<SystemTime> {
+    fn to_f64(&self, key: &SystemTime) -> f64 {
+        // Times before the origin give an error with the elapsed time the other way round
+        let seconds = match key.duration_since(self.origin) {
+            Ok(elapsed) => elapsed.as_secs_f64(),
+            Err(error) => -error.duration().as_secs_f64(),
+        };
+        seconds / (SECONDS_PER_DAY * self.days_per_year)
+    }
+}
+
+impl Default for YearFraction<SystemTime> {
+    /// Years of 365.25 days since the Unix epoch
+    fn default() -> Self {
+        Self::new(UNIX_EPOCH, 365.25)
+    }
+}
+
+/// Interpolator built and queried with keys of type K, converted to f64 by the abscissa.
+/// The conversion is resolved at compile time, so the evaluation costs the conversion of the key on top of
+/// the underlying `Interpolator`.
+#[derive(Debug)]
+pub struct KeyedInterpolator<K, C: Abscissa<K>> {
+    interpolator: Interpolator,
+    abscissa: C,
+    key: PhantomData<fn(&K)>,
+}
+
+impl<K, C: Abscissa<K>> KeyedInterpolator<K, C> {
+    /// Creates a new interpolator through the points (keys, y_values), see `Interpolator::new`
+    pub fn new(
+        keys: &[K],
+        y_values: Vec<f64>,
+        interpolation_type: InterpolationType,
+        extrap_strategy: ExtrapolationStrategy,
+        abscissa: C,
+    ) -> Self {
+        let x_values = keys.iter().map(|key| abscissa.to_f64(key)).collect();
+        Self {
+            interpolator: Interpolator::new(
+                x_values,
+                y_values,
+                interpolation_type,
+                extrap_strategy,
+            ),
+            abscissa,
+            key: PhantomData,
+        }
+    }
+
+    /// Performs interpolation at the given key
+    #[inline]
+    pub fn interpolate(&self, key: &K) -> f64 {
+        self.interpolator.interpolate(self.abscissa.to_f64(key))
+    }
+
+    /// Performs interpolation at the given key, see `Interpolator::try_interpolate`
+    pub fn try_interpolate(&self, key: &K) -> Result<f64, String> {
+        self.interpolator.try_interpolate(self.abscissa.to_f64(key))
+    }
+
+    /// Returns the abscissa of the key
+    pub fn abscissa(&self, key: &K) -> f64 {
+        self.abscissa.to_f64(key)
+    }
+
+    /// Returns the underlying interpolator over the converted abscissae
+    pub fn interpolator(&self) -> &Interpolator {
+        &self.interpolator
+    }
+}
@@ -0,0 +1,154 @@
+use super::{ExtrapolationStrategy, InterpolationType, Interpolator};
+use crate::linalg::{cholesky, cholesky_inverse, cholesky_solve};
+
+/// Result of a least-squares regression spline fit
+#[derive(Debug)]
+pub struct LeastSquaresSpline {
+    pub spline: Interpolator, // Natural cubic spline through the fitted knot values
+    pub knots: Vec<f64>,      // Knots including the boundaries of the data
+    pub knot_values: Vec<f64>, // Fitted parameters, the values of the spline at the knots
+    pub residuals: Vec<f64>,  // y - f(x) for every observation
+    pub residual_sum_of_squares: f64, // Weighted sum of squared residuals
+    pub degrees_of_freedom: usize, // Number of observations minus number of parameters
+    pub residual_variance: f64, // Weighted RSS divided by the degrees of freedom
+    pub covariance: Vec<Vec<f64>>, // Covariance matrix of the knot values
+}
+
+impl LeastSquaresSpline {
+    /// Evaluates the fitted spline at x
+    pub fn interpolate(&self, x: f64) -> f64 {
+        self.spline.interpolate(x)
+    }
+}
+
+/// Fits a natural cubic spline with the given interior knots to noisy observations by weighted least squares.
+/// The boundary knots are placed at the smallest and largest x, the parameters of the fit are the values
+/// of the spline at the knots, so the covariance can be read directly as the uncertainty of the knot values.
+pub fn fit_least_squares_spline(
+    x_values: &[f64],
+    y_values: &[f64],
+    weights: Option<&[f64]>,
+    interior_knots: &[f64],
+    extrap_strategy: ExtrapolationStrategy,
+) -> Result<LeastSquaresSpline, String> {
+    let m = x_values.len();
+    if y_values.len() != m {
+        return Err(String::from(
+            "x_values and y_values must have the same length",
+        ));
+    }
+    if x_values.iter().chain(y_values).any(|v| !v.is_finite()) {
+        return Err(String::from("x_values and y_values must be finite"));
+    }
+    if interior_knots.iter().any(|v| !v.is_finite()) {
+        return Err(String::from("Interior knots must be finite"));
+    }
+    let weights = match weights {
+        Some(w) if w.len() != m => {
+            return Err(String::from(
+                "weights must have the same length as x_values",
+            ))
+        }
+        Some(w) if w.iter().any(|&w| w <= 0.0 || !w.is_finite()) => {
+            return Err(String::from("weights must be positive and finite"))
+        }
+        Some(w) => w.to_vec(),
+        None => vec![1.0; m],
+    };
+
+    let x_min = x_values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let x_max = x_values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let mut knots = Vec::with_capacity(interior_knots.len() + 2);
+    knots.push(x_min);
+    knots.extend_from_slice(interior_knots);
+    knots.push(x_max);
+    if knots.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(String::from(
+            "Interior knots must be strictly increasing and lie strictly inside the data range",
+        ));
+    }
+    let k = knots.len();
+    if m < k {
+        return Err(format!(
+            "At least {} observations are required to fit {} knots",
+            k, k
+        ));
+    }
+
+    // The natural cubic spline is linear in the knot values, so the design matrix is made of the
+    // splines through the unit vectors evaluated at the observations
+    let mut design = vec![vec![0.0; k]; m];
+    for i in 0..k {
+        let mut unit = vec![0.0; k];
+        unit[i] = 1.0;
+        let basis = Interpolator::new(
+            knots.clone(),
+            unit,
+            InterpolationType::Cubic,
+            ExtrapolationStrategy::ExtendSpline,
+        );
+        for (row, &x) in design.iter_mut().zip(x_values) {
+            row[i] = basis.interpolate(x);
+        }
+    }
+
+    // Normal equations A^T W A p = A^T W y
+    let mut normal = vec![vec![0.0; k]; k];
+    let mut rhs = vec![0.0; k];
+    for ((row, &y), &w) in design.iter().zip(y_values).zip(&weights) {
+        for i in 0..k {
+            rhs[i] += w * row[i] * y;
+            for j in 0..k {
+                normal[i][j] += w * row[i] * row[j];
+            }
+        }
+    }
+    let l = cholesky(&normal).map_err(|_| {
+        String::from("Normal equations are singular, every knot interval needs observations")
+    })?;
+    let knot_values = cholesky_solve(&l, &rhs);
+
+    let residuals: Vec<f64> = design
+        .iter()
+        .zip(y_values)
+        .map(|(row, &y)| {
+            y - row
+                .iter()
+                .zip(&knot_values)
+                .map(|(a, p)| a * p)
+                .sum::<f64>()
+        })
+        .collect();
+    let residual_sum_of_squares = residuals
+        .iter()
+        .zip(&weights)
+        .map(|(r, w)| w * r * r)
+        .sum::<f64>();
+    let degrees_of_freedom = m - k;
+    // An exact fit leaves no degrees of freedom to estimate the noise
+    let residual_variance = if degrees_of_freedom > 0 {
+        residual_sum_of_squares / degrees_of_freedom as f64
+    } else {
+        f64::NAN
+    };
+    let covariance = cholesky_inverse(&l)
+        .into_iter()
+        .map(|row| row.into_iter().map(|v| v * residual_variance).collect())
+        .collect();
+
+    Ok(LeastSquaresSpline {
+        spline: Interpolator::new(
+            knots.clone(),
+            knot_values.clone(),
+            InterpolationType::Cubic,
+            extrap_strategy,
+        ),
+        knots,
+        knot_values,
+        residuals,
+        residual_sum_of_squares,
+        degrees_of_freedom,
+        residual_variance,
+        covariance,
+    })
+}
@@ -0,0 +1,420 @@
+mod barycentric;
+mod bezier;
+mod bounded;
+mod builder;
+mod cardinal;
+mod convexity;
+mod cross_validation;
+mod inverse;
+mod keyed;
+mod least_squares;
+mod monotone;
+mod neville;
+mod normalize;
+mod parametric;
+mod resample;
+mod sampling;
+mod sensitivities;
+mod serialization;
+mod simplify;
+mod sinc;
+mod tension;
+mod thin_plate;
+mod trigonometric;
+
+pub(crate) use barycentric::evaluate_barycentric;
+pub use bezier::{pdf_path, svg_path, BezierCurve};
+pub use builder::{GapPolicy, InterpolatorBuilder};
+pub use cross_validation::{
+    cross_validate, standard_schemes, CrossValidationReport, SchemeScore, ValidationMethod,
+};
+pub use keyed::{Abscissa, Identity, KeyedInterpolator, YearFraction};
+pub use least_squares::{fit_least_squares_spline, LeastSquaresSpline};
+pub use neville::{neville, rational_extrapolation};
+pub use normalize::{normalize, DuplicatePolicy, NormalizationReport};
+pub use parametric::ParametricSpline;
+pub use resample::{aggregate, geomspace, linspace, logspace, resample, Aggregation};
+pub use sampling::TabulatedDistribution;
+pub use simplify::simplify;
+pub use sinc::{resample_rational, SincWindow};
+pub use thin_plate::ThinPlateSpline;
+pub use trigonometric::{AngularInterpolator, TrigonometricInterpolator};
+
+/// Enum to define the type of interpolation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterpolationType {
+    Linear,           // Linear interpolation (order 1)
+    Quadratic,        // Quadratic spline interpolation (order 2)
+    Cubic,            // Cubic spline interpolation (order 3)
+    ConstantBackward, // Constant interpolation taking the previous value
+    ConstantForward,  // Constant interpolation taking the next value
+    Cardinal {
+        tension: f64, // Scales the slopes by (1 - tension), zero gives a Catmull-Rom spline
+        parameterization: CatmullRomParameterization,
+    }, // Local cubic interpolation with slopes taken from the neighbouring points
+    Tension(f64), // Exponential spline under tension sigma, zero gives the cubic spline and infinity the linear one
+    ConvexityPreserving, // Shape-preserving quadratic spline (Schumaker), convex data gives a convex interpolant
+    MonotoneCubic, // Monotone cubic Hermite spline (Fritsch-Carlson), monotone data gives a monotone interpolant
+    Sinc(SincWindow), // Windowed Whittaker-Shannon interpolation of band-limited signals on equally spaced knots
+    Bounded {
+        lower: f64, // Lower bound of the interpolated values, can be -infinity
+        upper: f64, // Upper bound of the interpolated values, can be infinity
+    }, // Natural cubic spline with slopes clamped so that the interpolant stays within [lower, upper], not monotone
+}
+
+impl InterpolationType {
+    /// Checks the parameters of the interpolation type and the requirements it puts on the points
+    pub(super) fn validate(&self, x_values: &[f64], y_values: &[f64]) -> Result<(), String> {
+        match *self {
+            InterpolationType::Cardinal { tension, .. } if !tension.is_finite() => {
+                Err(format!("Tension must be finite, got {}", tension))
+            }
+            InterpolationType::Tension(sigma) if sigma.is_nan() || sigma < 0.0 => {
+                Err(format!("Tension must be non-negative, got {}", sigma))
+            }
+            InterpolationType::Sinc(window) => window.validate().and(sinc::check_uniform(x_values)),
+            InterpolationType::Bounded { lower, upper } => {
+                if lower.is_nan() || upper.is_nan() || lower >= upper {
+                    return Err(format!(
+                        "Lower bound {} must be smaller than the upper bound {}",
+                        lower, upper
+                    ));
+                }
+                if y_values.iter().any(|y| !(lower..=upper).contains(y)) {
+                    return Err(format!(
+                        "y_values must be within the bounds [{}, {}]",
+                        lower, upper
+                    ));
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Enum to define the knot spacing used to derive the slopes of Catmull-Rom and cardinal splines
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CatmullRomParameterization {
+    Uniform,     // Knots are equally spaced, slopes are central differences
+    Centripetal, // Knot spacing is the square root of the distance between points
+    Chordal,     // Knot spacing is the distance between points
+}
+
+/// Enum to define the extrapolation strategy
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExtrapolationStrategy {
+    None,         // Do not extrapolate, panic on out-of-bounds
+    Constant,     // Use the closest y-value for out-of-bounds x
+    ExtendSpline, // Use the same spline function as interpolation
+}
+
+#[derive(Debug)]
+pub struct Interpolator {
+    x_values: Vec<f64>,
+    y_values: Vec<f64>,
+    b_coeffs: Vec<f64>,
+    c_coeffs: Vec<f64>,
+    d_coeffs: Vec<f64>,
+    interpolation_type: InterpolationType,
+    extrap_strategy: ExtrapolationStrategy,
+    gaps: Vec<(f64, f64)>, // Open intervals without data, see InterpolatorBuilder
+}
+
+impl Interpolator {
+    /// Creates a new Interpolator with the given points
+    pub fn new(
+        x_values: Vec<f64>,
+        y_values: Vec<f64>,
+        interpolation_type: InterpolationType,
+        extrap_strategy: ExtrapolationStrategy,
+    ) -> Self {
+        if x_values.len() != y_values.len() || x_values.len() < 2 {
+            panic!(
+                "x_values and y_values must have the same length and contain at least two points."
+            );
+        }
+        if x_values.iter().chain(&y_values).any(|v| v.is_nan()) {
+            panic!("x_values and y_values must not contain NaN, use InterpolatorBuilder to handle gaps.");
+        }
+        if x_values.windows(2).any(|w| w[0] >= w[1]) {
+            panic!("x_values must be strictly increasing, use InterpolatorBuilder to sort them.");
+        }
+        if let Err(message) = interpolation_type.validate(&x_values, &y_values) {
+            panic!("{}.", message);
+        }
+
+        // Precompute spline coefficients, the shape-preserving spline adds knots between the points
+        let (x_values, y_values, b_coeffs, c_coeffs, d_coeffs) = match interpolation_type {
+            InterpolationType::ConvexityPreserving => {
+                convexity::schumaker_spline(&x_values, &y_values)
+            }
+            _ => {
+                let (b_coeffs, c_coeffs, d_coeffs) =
+                    compute_spline_coefficients(&x_values, &y_values, &interpolation_type);
+                (x_values, y_values, b_coeffs, c_coeffs, d_coeffs)
+            }
+        };
+        Self {
+            x_values,
+            y_values,
+            b_coeffs,
+            c_coeffs,
+            d_coeffs,
+            interpolation_type,
+            extrap_strategy,
+            gaps: vec![],
+        }
+    }
+
+    /// Performs interpolation for a given x value using the specified type
+    pub fn interpolate(&self, x: f64) -> f64 {
+        if self.is_in_gap(x) {
+            return f64::NAN;
+        }
+        if let Some(j) = self.find_segment(x) {
+            // We found where the value is bracketed
+            return match self.interpolation_type {
+                InterpolationType::ConstantBackward => self.y_values[j],
+                InterpolationType::ConstantForward => self.y_values[j + 1],
+                _ => self.evaluate_segment(j, x),
+            };
+        }
+        if x < *self.x_values.first().unwrap() || x > *self.x_values.last().unwrap() {
+            return self.extrapolate(x);
+        }
+        unreachable!("This could not be reached as the x is either bracketed or extrapolated");
+    }
+
+    /// Performs interpolation for a given x value, returns an error instead of NaN inside a gap of the data
+    /// and instead of panicking when x is out of bounds and no extrapolation is enabled
+    pub fn try_interpolate(&self, x: f64) -> Result<f64, String> {
+        if self.is_in_gap(x) {
+            return Err(format!("Value x = {} falls into a gap of the data.", x));
+        }
+        let (x_first, x_last) = (self.x_values[0], self.x_values[self.x_values.len() - 1]);
+        if x.is_nan()
+            || (self.extrap_strategy == ExtrapolationStrategy::None
+                && !(x_first..=x_last).contains(&x))
+        {
+            return Err(format!(
+                "Value x = {} is out of bounds and no extrapolation is enabled.",
+                x
+            ));
+        }
+        Ok(self.interpolate(x))
+    }
+
+    /// Returns the intervals without data that were kept as gaps, as open intervals (start, end)
+    pub fn gaps(&self) -> &[(f64, f64)] {
+        &self.gaps
+    }
+
+    fn is_in_gap(&self, x: f64) -> bool {
+        self.gaps.iter().any(|&(start, end)| start < x && x < end)
+    }
+
+    /// Whether the segment j joins the two sides of a kept gap
+    fn spans_gap(&self, j: usize) -> bool {
+        self.is_in_gap((self.x_values[j] + self.x_values[j + 1]) / 2.0)
+    }
+
+    /// Returns the index of the first segment bracketing x, if any
+    fn find_segment(&self, x: f64) -> Option<usize> {
+        let segments = self.x_values.len() - 1;
+        if self.gaps.is_empty() {
+            // Number of knots after the first one that lie below x
+            let j = self.x_values[1..].partition_point(|&knot| knot < x);
+            return (j < segments && self.x_values[j] <= x).then_some(j);
+        }
+        let bracketing =
+            (0..segments).filter(|&j| self.x_values[j] <= x && x <= self.x_values[j + 1]);
+        // A segment across a gap is only taken at a knot without any other segment, i.e. a single point
+        // between two gaps, choosing the one that evaluates to the value at the knot
+        let segments: Vec<usize> = bracketing.collect();
+        segments
+            .iter()
+            .copied()
+            .find(|&j| !self.spans_gap(j))
+            .or(match self.interpolation_type {
+                InterpolationType::ConstantForward => segments.first().copied(),
+                _ => segments.last().copied(),
+            })
+    }
+
+    /// Evaluates the spline piece of segment j at x
+    fn evaluate_segment(&self, j: usize, x: f64) -> f64 {
+        match self.interpolation_type {
+            InterpolationType::Tension(sigma) => self.evaluate_tension(j, x, sigma),
+            InterpolationType::Sinc(window) => self.evaluate_sinc(x, &window),
+            // The limited slopes keep the control points, and so the segment, within the bounds. Ending exactly
+            // on the next knot avoids the rounding errors of the polynomial where the values touch a bound.
+            InterpolationType::Bounded { lower, upper } => {
+                if x == self.x_values[j + 1] {
+                    return self.y_values[j + 1];
+                }
+                let value = self.evaluate_polynomial(j, x);
+                let tolerance = 1e-9 * (1.0 + value.abs());
+                debug_assert!(value >= lower - tolerance && value <= upper + tolerance);
+                value
+            }
+            _ => self.evaluate_polynomial(j, x),
+        }
+    }
+
+    /// Evaluates the polynomial piece of segment j at x
+    fn evaluate_polynomial(&self, j: usize, x: f64) -> f64 {
+        let dx = x - self.x_values[j];
+        self.y_values[j]
+            + self.b_coeffs[j] * dx
+            + self.c_coeffs[j] * dx.powi(2)
+            + self.d_coeffs[j] * dx.powi(3)
+    }
+
+    /// Handles extrapolation for out-of-bounds x values
+    fn extrapolate(&self, x: f64) -> f64 {
+        match self.extrap_strategy {
+            ExtrapolationStrategy::None => {
+                panic!(
+                    "Value x = {} is out of bounds and no extrapolation is enabled.",
+                    x
+                );
+            }
+            ExtrapolationStrategy::Constant => {
+                if x < *self.x_values.first().unwrap() {
+                    return *self.y_values.first().unwrap();
+                }
+                *self.y_values.last().unwrap()
+            }
+            ExtrapolationStrategy::ExtendSpline => {
+                let left = x < *self.x_values.first().unwrap();
+                let j = if left { 0 } else { self.x_values.len() - 2 };
+                match self.interpolation_type {
+                    // The steps have no coefficients, the boundary steps are extended
+                    InterpolationType::ConstantBackward | InterpolationType::ConstantForward => {
+                        self.y_values[if left { 0 } else { j + 1 }]
+                    }
+                    // The end cubic would leave the bounds, the boundary knot is extended by a line instead
+                    InterpolationType::Bounded { lower, upper } => {
+                        let (knot, slope, outward) = if left {
+                            (0, self.b_coeffs[0], -1.0)
+                        } else {
+                            let h = self.x_values[j + 1] - self.x_values[j];
+                            let slope = self.b_coeffs[j]
+                                + 2.0 * self.c_coeffs[j] * h
+                                + 3.0 * self.d_coeffs[j] * h * h;
+                            (j + 1, slope, 1.0)
+                        };
+                        let slope = bounded::extension_slope(slope, outward, lower, upper);
+                        self.y_values[knot] + slope * (x - self.x_values[knot])
+                    }
+                    _ => self.evaluate_segment(j, x),
+                }
+            }
+        }
+    }
+}
+
+/// Computes the coefficients for cubic spline interpolation
+fn compute_spline_coefficients(
+    x: &[f64],
+    y: &[f64],
+    interpolation_type: &InterpolationType,
+) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    if matches!(
+        interpolation_type,
+        InterpolationType::ConstantForward | InterpolationType::ConstantBackward
+    ) {
+        return (vec![], vec![], vec![]);
+    }
+
+    let n = x.len() - 1; // Number of segments
+    let dx: Vec<f64> = (0..n).map(|i| x[i + 1] - x[i]).collect(); // Spacing between x-values
+    let dy: Vec<f64> = (0..n).map(|i| y[i + 1] - y[i]).collect(); // Spacing between y-values
+    let slopes: Vec<f64> = (0..n).map(|i| dy[i] / dx[i]).collect();
+
+    match interpolation_type {
+        InterpolationType::Linear => (slopes, vec![0.0; n], vec![0.0; n]),
+        // TODO: The code below could use more declarative way of solving equations, to be changed when I add matrix API
+        InterpolationType::Quadratic => {
+            let mut c = vec![0.0; n]; // Quadratic coefficients
+
+            for i in 1..n - 1 {
+                c[i] = (slopes[i] - slopes[i - 1]) / (dx[i - 1] * 2.0); // Derivative change over interval
+            }
+            c[n - 1] = 0.0; // Natural boundary at the last interval
+            (slopes, c, vec![0.0; n])
+        }
+        InterpolationType::Cubic => {
+            let mut alpha = vec![0.0; n - 1];
+            for i in 1..n {
+                alpha[i - 1] =
+                    3.0 / dx[i] * (y[i + 1] - y[i]) - 3.0 / dx[i - 1] * (y[i] - y[i - 1]);
+            }
+            let mut b = vec![0.0; n];
+            let mut c = vec![0.0; n + 1];
+            let mut d = vec![0.0; n];
+            let mut l = vec![1.0; n + 1];
+            let mut mu = vec![0.0; n];
+            let mut z = vec![0.0; n + 1];
+
+            for i in 1..n {
+                l[i] = 2.0 * (x[i + 1] - x[i - 1]) - dx[i - 1] * mu[i - 1];
+                mu[i] = dx[i] / l[i];
+                z[i] = (alpha[i - 1] - dx[i - 1] * z[i - 1]) / l[i];
+            }
+            for j in (0..n).rev() {
+                c[j] = z[j] - mu[j] * c[j + 1];
+                b[j] = dy[j] / dx[j] - dx[j] * (c[j + 1] + 2.0 * c[j]) / 3.0;
+                d[j] = (c[j + 1] - c[j]) / (3.0 * dx[j]);
+            }
+            (b, c, d)
+        }
+        InterpolationType::Cardinal {
+            tension,
+            parameterization,
+        } => {
+            let m = cardinal::cardinal_slopes(x, y, *tension, parameterization);
+            hermite_coefficients(&dx, &slopes, &m)
+        }
+        InterpolationType::MonotoneCubic => {
+            let m = monotone::monotone_slopes(x, y);
+            hermite_coefficients(&dx, &slopes, &m)
+        }
+        InterpolationType::Tension(sigma) => {
+            // Second derivatives at the knots are kept in place of the c coefficients
+            (
+                vec![],
+                tension::tension_second_derivatives(x, y, *sigma),
+                vec![],
+            )
+        }
+        InterpolationType::Bounded { lower, upper } => {
+            let (b, c, d) = compute_spline_coefficients(x, y, &InterpolationType::Cubic);
+            let mut m = b;
+            m.push(m[n - 1] + 2.0 * c[n - 1] * dx[n - 1] + 3.0 * d[n - 1] * dx[n - 1].powi(2));
+            bounded::limit_slopes(&dx, y, &mut m, *lower, *upper);
+            hermite_coefficients(&dx, &slopes, &m)
+        }
+        InterpolationType::Sinc(_) => {
+            // The samples themselves are the coefficients of the sinc series
+            (vec![], vec![], vec![])
+        }
+        _ => panic!(
+            "Interpolation type {:?} is not supported.",
+            interpolation_type
+        ),
+    }
+}
+
+/// Converts the slopes at the knots of a cubic Hermite spline into polynomial coefficients
+fn hermite_coefficients(dx: &[f64], secants: &[f64], m: &[f64]) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let n = dx.len();
+    let c = (0..n)
+        .map(|j| (3.0 * secants[j] - 2.0 * m[j] - m[j + 1]) / dx[j])
+        .collect();
+    let d = (0..n)
+        .map(|j| (m[j] + m[j + 1] - 2.0 * secants[j]) / (dx[j] * dx[j]))
+        .collect();
+    (m[..n].to_vec(), c, d)
+}
@@ -0,0 +1,36 @@
+/// Computes the slopes of the monotone cubic Hermite spline (Fritsch-Carlson, as in PCHIP) at every knot.
+/// Interior slopes are the weighted harmonic mean of the neighbouring secants, or zero at a local extremum,
+/// which keeps every segment monotone wherever the data is.
+pub(super) fn monotone_slopes(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len() - 1; // Number of segments
+    let h: Vec<f64> = (0..n).map(|i| x[i + 1] - x[i]).collect();
+    let secants: Vec<f64> = (0..n).map(|i| (y[i + 1] - y[i]) / h[i]).collect();
+    if n == 1 {
+        return vec![secants[0]; 2];
+    }
+
+    let mut m = vec![0.0; n + 1];
+    for i in 1..n {
+        let (s0, s1) = (secants[i - 1], secants[i]);
+        if s0 * s1 > 0.0 {
+            let w0 = 2.0 * h[i] + h[i - 1];
+            let w1 = h[i] + 2.0 * h[i - 1];
+            m[i] = (w0 + w1) / (w0 / s0 + w1 / s1);
+        }
+    }
+    m[0] = end_slope(h[0], h[1], secants[0], secants[1]);
+    m[n] = end_slope(h[n - 1], h[n - 2], secants[n - 1], secants[n - 2]);
+    m
+}
+
+/// Three-point slope at an end of the data, limited so that the end segment stays monotone
+fn end_slope(h0: f64, h1: f64, s0: f64, s1: f64) -> f64 {
+    let slope = ((2.0 * h0 + h1) * s0 - h0 * s1) / (h0 + h1);
+    if slope * s0 <= 0.0 {
+        0.0
+    } else if s0 * s1 < 0.0 && slope.abs() > 3.0 * s0.abs() {
+        3.0 * s0
+    } else {
+        slope
+    }
+}
@@ -0,0 +1,100 @@
+// Avoids 0 / 0 in the rational tableau when the interpolated value is exactly zero
+const TINY: f64 = 1e-25;
+
+/// Evaluates the polynomial through the points at x with Neville's algorithm and returns the value together with
+/// the last correction of the tableau as an error estimate. With x = 0 and the points (h_i, A(h_i)) of a method with
+/// step h, this is Richardson extrapolation to a zero step size.
+pub fn neville(x_values: &[f64], y_values: &[f64], x: f64) -> Result<(f64, f64), String> {
+    check_points(x_values, y_values)?;
+    let n = x_values.len();
+    let mut s = closest(x_values, x);
+    let mut value = y_values[s];
+    let mut error = 0.0;
+    // c and d hold the differences between the tableau entries of consecutive columns
+    let mut c = y_values.to_vec();
+    let mut d = y_values.to_vec();
+    for m in 1..n {
+        for i in 0..n - m {
+            let ho = x_values[i] - x;
+            let hp = x_values[i + m] - x;
+            let w = (c[i + 1] - d[i]) / (ho - hp);
+            d[i] = hp * w;
+            c[i] = ho * w;
+        }
+        // Follow the path through the tableau that stays centred on the closest point
+        error = if 2 * s < n - m {
+            c[s]
+        } else {
+            s -= 1;
+            d[s]
+        };
+        value += error;
+    }
+    Ok((value, error.abs()))
+}
+
+/// Evaluates the diagonal rational function through the points at x with the Bulirsch-Stoer algorithm and returns
+/// the value together with the last correction as an error estimate. Rational extrapolation often converges
+/// where polynomial extrapolation doesn't, e.g. for functions with poles near the extrapolation point.
+pub fn rational_extrapolation(
+    x_values: &[f64],
+    y_values: &[f64],
+    x: f64,
+) -> Result<(f64, f64), String> {
+    check_points(x_values, y_values)?;
+    if let Some(i) = x_values.iter().position(|&xi| xi == x) {
+        return Ok((y_values[i], 0.0));
+    }
+    let n = x_values.len();
+    let mut s = closest(x_values, x);
+    let mut value = y_values[s];
+    let mut error = 0.0;
+    let mut c = y_values.to_vec();
+    let mut d: Vec<f64> = y_values.iter().map(|y| y + TINY).collect();
+    for m in 1..n {
+        for i in 0..n - m {
+            let w = c[i + 1] - d[i];
+            let h = x_values[i + m] - x;
+            let t = (x_values[i] - x) * d[i] / h;
+            let denominator = t - c[i + 1];
+            if denominator == 0.0 {
+                return Err(format!("Rational interpolant has a pole at x = {}", x));
+            }
+            let ratio = w / denominator;
+            d[i] = c[i + 1] * ratio;
+            c[i] = t * ratio;
+        }
+        error = if 2 * s < n - m {
+            c[s]
+        } else {
+            s -= 1;
+            d[s]
+        };
+        value += error;
+    }
+    Ok((value, error.abs()))
+}
+
+fn check_points(x_values: &[f64], y_values: &[f64]) -> Result<(), String> {
+    if x_values.len() != y_values.len() || x_values.is_empty() {
+        return Err(String::from(
+            "x_values and y_values must have the same length and contain at least one point",
+        ));
+    }
+    if x_values.iter().chain(y_values).any(|v| !v.is_finite()) {
+        return Err(String::from("x_values and y_values must be finite"));
+    }
+    let mut sorted = x_values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    if sorted.windows(2).any(|w| w[0] == w[1]) {
+        return Err(String::from("x_values must be distinct"));
+    }
+    Ok(())
+}
+
+/// Returns the index of the point closest to x, where the tableau starts
+fn closest(x_values: &[f64], x: f64) -> usize {
+    (0..x_values.len())
+        .min_by(|&i, &j| (x_values[i] - x).abs().total_cmp(&(x_values[j] - x).abs()))
+        .unwrap()
+}
@@ -0,0 +1,71 @@
+/// Enum to define how points sharing the same x value are merged
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DuplicatePolicy {
+    Mean,  // Average of the values
+    First, // Value that comes first in the input
+    Last,  // Value that comes last in the input
+    Error, // Duplicates are rejected
+}
+
+/// Summary of the changes made to the data by `normalize`
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizationReport {
+    pub input_len: usize,              // Number of points given
+    pub output_len: usize,             // Number of points kept
+    pub reordered: bool,               // Whether the points had to be sorted
+    pub duplicates: Vec<(f64, usize)>, // x values that appeared more than once with their number of occurrences
+}
+
+/// Sorts the points by x and merges the points sharing the same x value using the given policy.
+/// Missing values (NaN) are ignored when merging, so a merged point is only missing if all its values are.
+pub fn normalize(
+    x_values: &[f64],
+    y_values: &[f64],
+    duplicate_policy: DuplicatePolicy,
+) -> Result<(Vec<f64>, Vec<f64>, NormalizationReport), String> {
+    if x_values.len() != y_values.len() {
+        return Err(String::from(
+            "x_values and y_values must have the same length.",
+        ));
+    }
+    if x_values.iter().any(|x| x.is_nan()) {
+        return Err(String::from("x_values must not contain NaN."));
+    }
+
+    // The sort is stable, so duplicates keep their input order, -0.0 and 0.0 are duplicates
+    let mut order: Vec<usize> = (0..x_values.len()).collect();
+    order.sort_by(|&i, &j| x_values[i].partial_cmp(&x_values[j]).unwrap());
+    let reordered = x_values.windows(2).any(|w| w[1] < w[0]);
+
+    let mut x_normalized = Vec::with_capacity(x_values.len());
+    let mut y_normalized = Vec::with_capacity(y_values.len());
+    let mut duplicates = Vec::new();
+    for group in order.chunk_by(|&i, &j| x_values[i] == x_values[j]) {
+        let x = x_values[group[0]];
+        if group.len() > 1 {
+            if duplicate_policy == DuplicatePolicy::Error {
+                return Err(format!("Value x = {} appears {} times.", x, group.len()));
+            }
+            duplicates.push((x, group.len()));
+        }
+        let mut values = group.iter().map(|&i| y_values[i]).filter(|y| !y.is_nan());
+        let y = match duplicate_policy {
+            DuplicatePolicy::First | DuplicatePolicy::Error => values.next(),
+            DuplicatePolicy::Last => values.next_back(),
+            DuplicatePolicy::Mean => {
+                let (sum, count) = values.fold((0.0, 0), |(sum, count), y| (sum + y, count + 1));
+                (count > 0).then(|| sum / count as f64)
+            }
+        };
+        x_normalized.push(x);
+        y_normalized.push(y.unwrap_or(f64::NAN));
+    }
+
+    let report = NormalizationReport {
+        input_len: x_values.len(),
+        output_len: x_normalized.len(),
+        reordered,
+        duplicates,
+    };
+    Ok((x_normalized, y_normalized, report))
+}
@@ -0,0 +1,195 @@
+use super::inverse::newton_bisection;
+use super::{ExtrapolationStrategy, InterpolationType, Interpolator};
+
+// Nodes and weights of the 5-point Gauss-Legendre rule on [-1, 1]
+const GAUSS_NODES: [f64; 5] = [
+    0.0,
+    -0.538_469_310_105_683_1,
+    0.538_469_310_105_683_1,
+    -0.906_179_845_938_664,
+    0.906_179_845_938_664,
+];
+const GAUSS_WEIGHTS: [f64; 5] = [
+    0.568_888_888_888_888_9,
+    0.478_628_670_499_366_5,
+    0.478_628_670_499_366_5,
+    0.236_926_885_056_189_1,
+    0.236_926_885_056_189_1,
+];
+// Number of panels of the Gauss-Legendre rule per segment
+const PANELS: usize = 4;
+
+/// Spatial curve through points in N dimensions, parameterised by arc length.
+/// Each coordinate is a natural cubic spline over the chord-length knots, the arc length is integrated
+/// by Gauss-Legendre quadrature and inverted with Newton's method to evaluate the curve at a given length.
+#[derive(Debug)]
+pub struct ParametricSpline<const N: usize> {
+    knots: Vec<f64>,
+    coordinates: Vec<Interpolator>,
+    arc_lengths: Vec<f64>,
+}
+
+impl<const N: usize> ParametricSpline<N> {
+    /// Creates a new ParametricSpline through the given points, consecutive points must differ
+    pub fn new(points: &[[f64; N]]) -> Result<Self, String> {
+        if points.len() < 2 {
+            return Err(String::from("At least two points are required"));
+        }
+        let mut knots = vec![0.0];
+        for w in points.windows(2) {
+            let chord = distance(&w[0], &w[1]);
+            if !(chord > 0.0 && chord.is_finite()) {
+                return Err(String::from(
+                    "Consecutive points must be distinct and finite",
+                ));
+            }
+            knots.push(knots[knots.len() - 1] + chord);
+        }
+        let coordinates = (0..N)
+            .map(|k| {
+                Interpolator::new(
+                    knots.clone(),
+                    points.iter().map(|p| p[k]).collect(),
+                    InterpolationType::Cubic,
+                    ExtrapolationStrategy::None,
+                )
+            })
+            .collect();
+
+        let mut spline = Self {
+            knots,
+            coordinates,
+            arc_lengths: vec![0.0],
+        };
+        for j in 0..points.len() - 1 {
+            let length = spline.segment_length(j, spline.knots[j + 1]);
+            spline.arc_lengths.push(spline.arc_lengths[j] + length);
+        }
+        Ok(spline)
+    }
+
+    /// Returns the total length of the curve
+    pub fn length(&self) -> f64 {
+        self.arc_lengths[self.arc_lengths.len() - 1]
+    }
+
+    /// Returns the arc length at each of the points
+    pub fn arc_lengths(&self) -> &[f64] {
+        &self.arc_lengths
+    }
+
+    /// Returns the point of the curve at arc length s
+    pub fn position(&self, s: f64) -> [f64; N] {
+        let (j, u) = self.parameter(s);
+        std::array::from_fn(|k| self.coordinates[k].evaluate_polynomial(j, u))
+    }
+
+    /// Returns the unit tangent at arc length s
+    pub fn tangent(&self, s: f64) -> [f64; N] {
+        let (first, _) = self.derivatives(s);
+        let speed = norm(&first);
+        first.map(|v| v / speed)
+    }
+
+    /// Returns the unit principal normal at arc length s, pointing towards the centre of curvature.
+    /// It is zero where the curve is straight.
+    pub fn normal(&self, s: f64) -> [f64; N] {
+        let (first, second) = self.derivatives(s);
+        // Component of the acceleration orthogonal to the velocity
+        let projection = dot(&first, &second) / dot(&first, &first);
+        let normal: [f64; N] = std::array::from_fn(|k| second[k] - projection * first[k]);
+        let size = norm(&normal);
+        if size <= f64::EPSILON * norm(&second) {
+            return [0.0; N];
+        }
+        normal.map(|v| v / size)
+    }
+
+    /// Returns the curvature at arc length s, i.e. the inverse of the radius of the osculating circle
+    pub fn curvature(&self, s: f64) -> f64 {
+        let (first, second) = self.derivatives(s);
+        let speed_squared = dot(&first, &first);
+        let cross_squared =
+            (speed_squared * dot(&second, &second) - dot(&first, &second).powi(2)).max(0.0);
+        cross_squared.sqrt() / speed_squared.powf(1.5)
+    }
+
+    /// Finds the segment and the chord-length parameter where the arc length equals s
+    fn parameter(&self, s: f64) -> (usize, f64) {
+        let length = self.length();
+        if !(0.0..=length).contains(&s) {
+            panic!("Arc length s = {} is out of bounds [0, {}].", s, length);
+        }
+        let j = self.arc_lengths[1..]
+            .partition_point(|&l| l < s)
+            .min(self.knots.len() - 2);
+        let target = s - self.arc_lengths[j];
+        let (lo, hi) = (self.knots[j], self.knots[j + 1]);
+        let segment = self.arc_lengths[j + 1] - self.arc_lengths[j];
+        // The speed is close to one with chord-length knots, so the proportional guess is already close
+        let u = newton_bisection(
+            |u| self.segment_length(j, u) - target,
+            |u| norm(&self.first_derivative(j, u)),
+            (lo, hi),
+            lo + (hi - lo) * target / segment,
+            1e-14 * self.knots[self.knots.len() - 1],
+        );
+        (j, u)
+    }
+
+    /// Integrates the speed along segment j from its first knot to u
+    fn segment_length(&self, j: usize, u: f64) -> f64 {
+        let start = self.knots[j];
+        let width = (u - start) / PANELS as f64;
+        (0..PANELS)
+            .map(|panel| {
+                let centre = start + width * (panel as f64 + 0.5);
+                GAUSS_NODES
+                    .iter()
+                    .zip(GAUSS_WEIGHTS)
+                    .map(|(node, weight)| {
+                        weight * norm(&self.first_derivative(j, centre + node * width / 2.0))
+                    })
+                    .sum::<f64>()
+                    * width
+                    / 2.0
+            })
+            .sum()
+    }
+
+    fn first_derivative(&self, j: usize, u: f64) -> [f64; N] {
+        std::array::from_fn(|k| self.coordinates[k].polynomial_derivatives(j, u).0)
+    }
+
+    /// Returns the first and second derivatives with respect to the chord-length parameter at arc length s
+    fn derivatives(&self, s: f64) -> ([f64; N], [f64; N]) {
+        let (j, u) = self.parameter(s);
+        let derivatives: [(f64, f64); N] =
+            std::array::from_fn(|k| self.coordinates[k].polynomial_derivatives(j, u));
+        (derivatives.map(|d| d.0), derivatives.map(|d| d.1))
+    }
+}
+
+impl Interpolator {
+    /// Returns the first and second derivatives of the polynomial piece of segment j at x
+    fn polynomial_derivatives(&self, j: usize, x: f64) -> (f64, f64) {
+        let dx = x - self.x_values[j];
+        (
+            self.b_coeffs[j] + 2.0 * self.c_coeffs[j] * dx + 3.0 * self.d_coeffs[j] * dx * dx,
+            2.0 * self.c_coeffs[j] + 6.0 * self.d_coeffs[j] * dx,
+        )
+    }
+}
+
+fn dot<const N: usize>(a: &[f64; N], b: &[f64; N]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn norm<const N: usize>(a: &[f64; N]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+fn distance<const N: usize>(a: &[f64; N], b: &[f64; N]) -> f64 {
+    let difference: [f64; N] = std::array::from_fn(|k| b[k] - a[k]);
+    norm(&difference)
+}
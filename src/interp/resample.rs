@@ -0,0 +1,154 @@
+use super::{ExtrapolationStrategy, InterpolationType, Interpolator};
+
+/// Enum to define how the observations falling into a bin are combined when downsampling
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Aggregation {
+    Mean,  // Average of the values in the bin
+    First, // Value with the smallest x in the bin
+    Last,  // Value with the largest x in the bin
+    Sum,   // Sum of the values in the bin, zero for an empty bin
+}
+
+/// Interpolates the observations (x, y) onto the target grid
+pub fn resample(
+    x_values: &[f64],
+    y_values: &[f64],
+    grid: &[f64],
+    interpolation_type: InterpolationType,
+    extrap_strategy: ExtrapolationStrategy,
+) -> Result<Vec<f64>, String> {
+    if x_values.len() != y_values.len() || x_values.len() < 2 {
+        return Err(String::from(
+            "x_values and y_values must have the same length and contain at least two points",
+        ));
+    }
+    if x_values.iter().chain(y_values).any(|v| !v.is_finite()) {
+        return Err(String::from(
+            "x_values and y_values must be finite, use InterpolatorBuilder to handle gaps",
+        ));
+    }
+    if x_values.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(String::from("x_values must be strictly increasing"));
+    }
+    interpolation_type.validate(x_values, y_values)?;
+    let (x_first, x_last) = (x_values[0], x_values[x_values.len() - 1]);
+    if grid.iter().any(|&x| {
+        x.is_nan()
+            || (extrap_strategy == ExtrapolationStrategy::None && !(x_first..=x_last).contains(&x))
+    }) {
+        return Err(String::from(
+            "The grid must lie within the data when no extrapolation is enabled",
+        ));
+    }
+
+    let interpolator = Interpolator::new(
+        x_values.to_vec(),
+        y_values.to_vec(),
+        interpolation_type,
+        extrap_strategy,
+    );
+    Ok(grid.iter().map(|&x| interpolator.interpolate(x)).collect())
+}
+
+/// Aggregates the observations (x, y) over the bins [grid_i, grid_i+1), the last bin also contains its right edge.
+/// Returns one value per bin, i.e. grid.len() - 1 values, labelled by the left edge of the bin.
+/// Observations outside of the grid are ignored and empty bins give NaN, except for the sum which gives zero.
+pub fn aggregate(
+    x_values: &[f64],
+    y_values: &[f64],
+    grid: &[f64],
+    aggregation: Aggregation,
+) -> Result<Vec<f64>, String> {
+    if x_values.len() != y_values.len() {
+        return Err(String::from(
+            "x_values and y_values must have the same length",
+        ));
+    }
+    if grid.len() < 2
+        || grid
+            .windows(2)
+            .any(|w| w[0] >= w[1] || w[0].is_nan() || w[1].is_nan())
+    {
+        return Err(String::from(
+            "The grid must be strictly increasing and contain at least two points",
+        ));
+    }
+
+    let bins = grid.len() - 1;
+    let mut sums = vec![0.0; bins];
+    let mut counts = vec![0usize; bins];
+    // (x, y) of the first and the last observation of each bin
+    let mut first: Vec<Option<(f64, f64)>> = vec![None; bins];
+    let mut last: Vec<Option<(f64, f64)>> = vec![None; bins];
+    for (&x, &y) in x_values.iter().zip(y_values) {
+        let Some(bin) = find_bin(grid, x) else {
+            continue;
+        };
+        sums[bin] += y;
+        counts[bin] += 1;
+        if first[bin].is_none_or(|(x_first, _)| x < x_first) {
+            first[bin] = Some((x, y));
+        }
+        if last[bin].is_none_or(|(x_last, _)| x >= x_last) {
+            last[bin] = Some((x, y));
+        }
+    }
+
+    Ok((0..bins)
+        .map(|bin| match aggregation {
+            Aggregation::Sum => sums[bin],
+            Aggregation::Mean if counts[bin] > 0 => sums[bin] / counts[bin] as f64,
+            Aggregation::First => first[bin].map_or(f64::NAN, |(_, y)| y),
+            Aggregation::Last => last[bin].map_or(f64::NAN, |(_, y)| y),
+            Aggregation::Mean => f64::NAN,
+        })
+        .collect())
+}
+
+/// Returns the index of the bin containing x, if any
+fn find_bin(grid: &[f64], x: f64) -> Option<usize> {
+    let bins = grid.len() - 1;
+    if x < grid[0] || x > grid[bins] || x.is_nan() {
+        return None;
+    }
+    // Number of edges at or below x, the right edge of the grid belongs to the last bin
+    Some((grid.partition_point(|&edge| edge <= x) - 1).min(bins - 1))
+}
+
+/// Returns n evenly spaced points from start to end, both included
+pub fn linspace(start: f64, end: f64, n: usize) -> Vec<f64> {
+    match n {
+        0 => vec![],
+        1 => vec![start],
+        _ => {
+            let step = (end - start) / (n - 1) as f64;
+            let mut points: Vec<f64> = (0..n).map(|i| start + step * i as f64).collect();
+            points[n - 1] = end;
+            points
+        }
+    }
+}
+
+/// Returns n points from base^start to base^end, evenly spaced on a log scale
+pub fn logspace(start: f64, end: f64, n: usize, base: f64) -> Vec<f64> {
+    linspace(start, end, n)
+        .into_iter()
+        .map(|exponent| base.powf(exponent))
+        .collect()
+}
+
+/// Returns n points from start to end in geometric progression, both included
+pub fn geomspace(start: f64, end: f64, n: usize) -> Vec<f64> {
+    if start == 0.0 || end == 0.0 || start.signum() != end.signum() {
+        panic!("Geometric sequence bounds must be non-zero and of the same sign.");
+    }
+    let mut points: Vec<f64> = logspace(start.abs().ln(), end.abs().ln(), n, std::f64::consts::E)
+        .into_iter()
+        .map(|point| start.signum() * point)
+        .collect();
+    if n > 1 {
+        points[0] = start;
+        points[n - 1] = end;
+    }
+    points
+}
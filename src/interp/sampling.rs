@@ -0,0 +1,105 @@
+use super::{ExtrapolationStrategy, InterpolationType, Interpolator};
+
+/// Probability distribution given by a tabulated CDF, interpolated with a monotone cubic spline.
+/// Quantiles invert the spline on the segment that brackets the probability, which gives inverse transform sampling.
+#[derive(Debug)]
+pub struct TabulatedDistribution {
+    cdf: Interpolator,
+    probabilities: Vec<f64>,
+}
+
+impl TabulatedDistribution {
+    /// Creates the distribution from CDF values at increasing x. The values must be non-decreasing,
+    /// they are rescaled so that the CDF goes from 0 at the first x to 1 at the last one.
+    pub fn from_cdf(x_values: &[f64], cdf_values: &[f64]) -> Result<Self, String> {
+        check_table(x_values, cdf_values)?;
+        if cdf_values.windows(2).any(|w| w[1] < w[0]) {
+            return Err(String::from("CDF values must be non-decreasing"));
+        }
+        let (first, last) = (cdf_values[0], cdf_values[cdf_values.len() - 1]);
+        if last <= first {
+            return Err(String::from("CDF must increase over the table"));
+        }
+        let mut probabilities: Vec<f64> = cdf_values
+            .iter()
+            .map(|c| (c - first) / (last - first))
+            .collect();
+        // Exact end points keep the quantiles of 0 and 1 on the support
+        let n = probabilities.len();
+        probabilities[n - 1] = 1.0;
+        let cdf = Interpolator::new(
+            x_values.to_vec(),
+            probabilities.clone(),
+            InterpolationType::MonotoneCubic,
+            ExtrapolationStrategy::Constant,
+        );
+        Ok(Self { cdf, probabilities })
+    }
+
+    /// Creates the distribution from non-negative density values at increasing x, which need not be normalised.
+    /// The CDF at the points is integrated with the trapezoidal rule.
+    pub fn from_pdf(x_values: &[f64], pdf_values: &[f64]) -> Result<Self, String> {
+        check_table(x_values, pdf_values)?;
+        if pdf_values.iter().any(|&p| p < 0.0) {
+            return Err(String::from("PDF values must be non-negative"));
+        }
+        let mut cdf_values = Vec::with_capacity(x_values.len());
+        cdf_values.push(0.0);
+        for j in 0..x_values.len() - 1 {
+            let area = (x_values[j + 1] - x_values[j]) * (pdf_values[j] + pdf_values[j + 1]) / 2.0;
+            cdf_values.push(cdf_values[j] + area);
+        }
+        Self::from_cdf(x_values, &cdf_values)
+    }
+
+    /// Evaluates the CDF at x, it's 0 before the table and 1 after it
+    pub fn cdf(&self, x: f64) -> f64 {
+        self.cdf.interpolate(x)
+    }
+
+    /// Returns the smallest x where the CDF reaches the probability p
+    pub fn quantile(&self, p: f64) -> Result<f64, String> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(format!("Probability {} is outside of [0, 1]", p));
+        }
+        // The CDF is monotone, so the first knot reaching p bounds the segment that holds the quantile
+        let k = self.probabilities.partition_point(|&c| c < p);
+        if k == 0 || self.probabilities[k] == p {
+            return Ok(self.cdf.x_values[k]);
+        }
+        self.cdf.solve_segment(k - 1, p)
+    }
+
+    /// Draws a sample by inverse transform of a uniform number on [0, 1] from the given source
+    pub fn sample(&self, uniform: &mut impl FnMut() -> f64) -> f64 {
+        let u = uniform();
+        self.quantile(u)
+            .unwrap_or_else(|_| panic!("Uniform source returned {}, outside of [0, 1].", u))
+    }
+
+    /// Draws the given number of samples, see `sample`
+    pub fn samples(&self, count: usize, uniform: &mut impl FnMut() -> f64) -> Vec<f64> {
+        (0..count).map(|_| self.sample(uniform)).collect()
+    }
+
+    /// Returns the range of x where the distribution is tabulated
+    pub fn support(&self) -> (f64, f64) {
+        let x = &self.cdf.x_values;
+        (x[0], x[x.len() - 1])
+    }
+}
+
+fn check_table(x_values: &[f64], values: &[f64]) -> Result<(), String> {
+    if x_values.len() != values.len() || x_values.len() < 2 {
+        return Err(String::from(
+            "x_values and the table must have the same length and contain at least two points",
+        ));
+    }
+    if x_values.iter().chain(values).any(|v| !v.is_finite()) {
+        return Err(String::from("x_values and the table must be finite"));
+    }
+    if x_values.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(String::from("x_values must be strictly increasing"));
+    }
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use super::builder::split_at_gaps;
+use super::tension::{tension_basis, tension_system};
+use super::{CatmullRomParameterization, ExtrapolationStrategy, InterpolationType, Interpolator};
+use crate::linalg::solve_tridiagonal;
+
+impl Interpolator {
+    /// Computes the gradient of the interpolated value at x with respect to every node value y_i.
+    /// The supported interpolants are linear in the node values, so the weights are computed analytically
+    /// and f(x) = sum(w_i * y_i) holds for the returned weights. Panics for the interpolants whose
+    /// shape depends on the node values, e.g. non-uniform Catmull-Rom splines. Inside a gap of the data
+    /// all the weights are NaN.
+    pub fn node_sensitivities(&self, x: f64) -> Vec<f64> {
+        let n = self.x_values.len();
+        let mut weights = vec![0.0; n];
+        if self.is_in_gap(x) {
+            return vec![f64::NAN; n];
+        }
+        let pieces = split_at_gaps(&self.x_values, &self.gaps);
+        if pieces.len() > 1 {
+            // Every piece between the gaps is fitted on its own, see InterpolatorBuilder
+            let last = pieces.len() - 1;
+            let piece = pieces
+                .iter()
+                .find(|piece| x <= self.x_values[piece.end - 1])
+                .unwrap_or(&pieces[last])
+                .clone();
+            if piece.len() == 1 {
+                weights[piece.start] = 1.0;
+            } else {
+                let fitted = Interpolator::new(
+                    self.x_values[piece.clone()].to_vec(),
+                    self.y_values[piece.clone()].to_vec(),
+                    self.interpolation_type,
+                    self.extrap_strategy,
+                );
+                weights[piece].copy_from_slice(&fitted.node_sensitivities(x));
+            }
+            return weights;
+        }
+        let (x_first, x_last) = (self.x_values[0], self.x_values[n - 1]);
+
+        let j = match self.find_segment(x) {
+            Some(j) => j,
+            None => match self.extrap_strategy {
+                ExtrapolationStrategy::None => {
+                    panic!(
+                        "Value x = {} is out of bounds and no extrapolation is enabled.",
+                        x
+                    );
+                }
+                ExtrapolationStrategy::Constant => {
+                    weights[if x < x_first { 0 } else { n - 1 }] = 1.0;
+                    return weights;
+                }
+                ExtrapolationStrategy::ExtendSpline => {
+                    if x < x_first {
+                        0
+                    } else {
+                        n - 2
+                    }
+                }
+            },
+        };
+
+        let dx = x - self.x_values[j];
+        let h = self.x_values[j + 1] - self.x_values[j];
+        match self.interpolation_type {
+            InterpolationType::ConstantBackward => {
+                weights[if x > x_last { n - 1 } else { j }] = 1.0;
+            }
+            InterpolationType::ConstantForward => {
+                weights[if x < x_first { 0 } else { j + 1 }] = 1.0;
+            }
+            InterpolationType::Linear => {
+                weights[j] = 1.0 - dx / h;
+                weights[j + 1] = dx / h;
+            }
+            InterpolationType::Quadratic => {
+                weights[j] = 1.0 - dx / h;
+                weights[j + 1] = dx / h;
+                // c_j = (s_j - s_{j-1}) / (2 * h_{j-1}) on all but the first and the last segments
+                if j >= 1 && j + 2 < n {
+                    let h_prev = self.x_values[j] - self.x_values[j - 1];
+                    let scale = dx * dx / (2.0 * h_prev);
+                    weights[j + 1] += scale / h;
+                    weights[j] -= scale * (1.0 / h + 1.0 / h_prev);
+                    weights[j - 1] += scale / h_prev;
+                }
+            }
+            InterpolationType::Cubic => {
+                weights[j] = 1.0 - dx / h;
+                weights[j + 1] = dx / h;
+                if n > 2 {
+                    // The natural cubic spline is the spline under zero tension
+                    self.add_curvature_sensitivities(j, dx / h, 0.0, &mut weights);
+                }
+            }
+            InterpolationType::Tension(sigma) => {
+                weights[j] = 1.0 - dx / h;
+                weights[j + 1] = dx / h;
+                // An infinite tension gives the broken line, whose curvature terms vanish
+                if n > 2 && sigma.is_finite() {
+                    self.add_curvature_sensitivities(j, dx / h, sigma, &mut weights);
+                }
+            }
+            InterpolationType::Cardinal {
+                tension,
+                parameterization: CatmullRomParameterization::Uniform,
+            } => {
+                // Hermite form: f = y_j + h * (t - 2t^2 + t^3) * m_j + h * (t^3 - t^2) * m_{j+1} + h * (3t^2 - 2t^3) * s_j
+                let t = dx / h;
+                weights[j] += 1.0;
+                let secant_weight = 3.0 * t * t - 2.0 * t.powi(3);
+                weights[j] -= secant_weight;
+                weights[j + 1] += secant_weight;
+                for (i, slope_weight) in [
+                    (j, h * (t - 2.0 * t * t + t.powi(3))),
+                    (j + 1, h * (t.powi(3) - t * t)),
+                ] {
+                    // Slopes are scaled central differences, one-sided at the end points
+                    let (left, right) = (i.saturating_sub(1), (i + 1).min(n - 1));
+                    let scale = (1.0 - tension) * slope_weight
+                        / (self.x_values[right] - self.x_values[left]);
+                    weights[right] += scale;
+                    weights[left] -= scale;
+                }
+            }
+            InterpolationType::Sinc(window) => {
+                for (i, weight) in self.sinc_taps(x, &window) {
+                    weights[i] += weight;
+                }
+            }
+            InterpolationType::Cardinal { .. }
+            | InterpolationType::ConvexityPreserving
+            | InterpolationType::MonotoneCubic
+            | InterpolationType::Bounded { .. } => panic!(
+                "Node sensitivities are not supported for {:?} interpolation.",
+                self.interpolation_type
+            ),
+        }
+        weights
+    }
+
+    /// Computes the node sensitivities for each of the given x values
+    pub fn node_sensitivities_batch(&self, x_values: &[f64]) -> Vec<Vec<f64>> {
+        x_values
+            .iter()
+            .map(|&x| self.node_sensitivities(x))
+            .collect()
+    }
+
+    /// Adds the contribution of the node values through the second derivatives z of the spline under tension.
+    /// The interior second derivatives solve T * z = R * y, so dz/dy = T^-1 * R and, T being symmetric,
+    /// the gradient of f along z can be pulled back with a single tridiagonal solve.
+    fn add_curvature_sensitivities(&self, j: usize, t: f64, sigma: f64, weights: &mut [f64]) {
+        let x = &self.x_values;
+        let n = x.len() - 1; // Number of segments
+        let h: Vec<f64> = (0..n).map(|i| x[i + 1] - x[i]).collect();
+
+        // Gradient of f with respect to z_j and z_j+1, the boundary second derivatives are fixed to zero
+        let mut gradient = vec![0.0; n - 1];
+        if j >= 1 {
+            gradient[j - 1] = tension_basis(sigma, h[j], 1.0 - t);
+        }
+        if j + 1 < n {
+            gradient[j] = tension_basis(sigma, h[j], t);
+        }
+
+        let (off_diagonal, diagonal) = tension_system(&h, sigma);
+        let main: Vec<f64> = (1..n).map(|i| diagonal[i - 1] + diagonal[i]).collect();
+        let z = solve_tridiagonal(
+            &off_diagonal[1..n - 1],
+            &main,
+            &off_diagonal[1..n - 1],
+            &gradient,
+        );
+
+        // Row i of R maps y to the change of secant (y_i+1 - y_i) / h_i - (y_i - y_i-1) / h_i-1
+        for i in 1..n {
+            let zi = z[i - 1];
+            weights[i - 1] += zi / h[i - 1];
+            weights[i] -= zi * (1.0 / h[i] + 1.0 / h[i - 1]);
+            weights[i + 1] += zi / h[i];
+        }
+    }
+}
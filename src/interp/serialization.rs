@@ -0,0 +1,229 @@
+use super::{
+    CatmullRomParameterization, ExtrapolationStrategy, InterpolationType, Interpolator, SincWindow,
+};
+use crate::json::JsonValue;
+use std::fmt;
+use std::str::FromStr;
+
+const FORMAT: &str = "numerics-rs/interpolator";
+const VERSION: usize = 1;
+
+impl fmt::Display for InterpolationType {
+    /// Writes the type as its name followed by the parameters in brackets, e.g. `Tension(2.5)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterpolationType::Linear => write!(f, "Linear"),
+            InterpolationType::Quadratic => write!(f, "Quadratic"),
+            InterpolationType::Cubic => write!(f, "Cubic"),
+            InterpolationType::ConstantBackward => write!(f, "ConstantBackward"),
+            InterpolationType::ConstantForward => write!(f, "ConstantForward"),
+            InterpolationType::Cardinal {
+                tension,
+                parameterization,
+            } => write!(f, "Cardinal({}, {})", tension, parameterization),
+            InterpolationType::Tension(sigma) => write!(f, "Tension({})", sigma),
+            InterpolationType::ConvexityPreserving => write!(f, "ConvexityPreserving"),
+            InterpolationType::MonotoneCubic => write!(f, "MonotoneCubic"),
+            InterpolationType::Bounded { lower, upper } => {
+                write!(f, "Bounded({}, {})", lower, upper)
+            }
+            InterpolationType::Sinc(SincWindow::Lanczos(a)) => write!(f, "Sinc(Lanczos, {})", a),
+            InterpolationType::Sinc(SincWindow::Kaiser { half_width, beta }) => {
+                write!(f, "Sinc(Kaiser, {}, {})", half_width, beta)
+            }
+        }
+    }
+}
+
+impl FromStr for InterpolationType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, parameters) = split_parameters(s)?;
+        let parse_f64 = |text: &str| {
+            text.parse::<f64>()
+                .map_err(|_| format!("Invalid parameter \"{}\" in \"{}\"", text, s))
+        };
+        let parse_usize = |text: &str| {
+            text.parse::<usize>()
+                .map_err(|_| format!("Invalid parameter \"{}\" in \"{}\"", text, s))
+        };
+        match (name, parameters.as_slice()) {
+            ("Linear", []) => Ok(InterpolationType::Linear),
+            ("Quadratic", []) => Ok(InterpolationType::Quadratic),
+            ("Cubic", []) => Ok(InterpolationType::Cubic),
+            ("ConstantBackward", []) => Ok(InterpolationType::ConstantBackward),
+            ("ConstantForward", []) => Ok(InterpolationType::ConstantForward),
+            ("Cardinal", [tension, parameterization]) => Ok(InterpolationType::Cardinal {
+                tension: parse_f64(tension)?,
+                parameterization: parameterization.parse()?,
+            }),
+            ("Tension", [sigma]) => Ok(InterpolationType::Tension(parse_f64(sigma)?)),
+            ("ConvexityPreserving", []) => Ok(InterpolationType::ConvexityPreserving),
+            ("MonotoneCubic", []) => Ok(InterpolationType::MonotoneCubic),
+            ("Bounded", [lower, upper]) => Ok(InterpolationType::Bounded {
+                lower: parse_f64(lower)?,
+                upper: parse_f64(upper)?,
+            }),
+            ("Sinc", ["Lanczos", a]) => Ok(InterpolationType::Sinc(SincWindow::Lanczos(
+                parse_usize(a)?,
+            ))),
+            ("Sinc", ["Kaiser", half_width, beta]) => {
+                Ok(InterpolationType::Sinc(SincWindow::Kaiser {
+                    half_width: parse_usize(half_width)?,
+                    beta: parse_f64(beta)?,
+                }))
+            }
+            _ => Err(format!("Unknown interpolation type \"{}\"", s)),
+        }
+    }
+}
+
+impl fmt::Display for CatmullRomParameterization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl FromStr for CatmullRomParameterization {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Uniform" => Ok(CatmullRomParameterization::Uniform),
+            "Centripetal" => Ok(CatmullRomParameterization::Centripetal),
+            "Chordal" => Ok(CatmullRomParameterization::Chordal),
+            _ => Err(format!("Unknown parameterization \"{}\"", s)),
+        }
+    }
+}
+
+impl fmt::Display for ExtrapolationStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl FromStr for ExtrapolationStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "None" => Ok(ExtrapolationStrategy::None),
+            "Constant" => Ok(ExtrapolationStrategy::Constant),
+            "ExtendSpline" => Ok(ExtrapolationStrategy::ExtendSpline),
+            _ => Err(format!("Unknown extrapolation strategy \"{}\"", s)),
+        }
+    }
+}
+
+/// Splits `Name(a, b)` into the name and the trimmed parameters, a name without brackets has no parameters
+fn split_parameters(s: &str) -> Result<(&str, Vec<&str>), String> {
+    let s = s.trim();
+    match s.split_once('(') {
+        None => Ok((s, vec![])),
+        Some((name, rest)) => {
+            let parameters = rest
+                .strip_suffix(')')
+                .ok_or_else(|| format!("Missing closing bracket in \"{}\"", s))?;
+            Ok((name.trim(), parameters.split(',').map(str::trim).collect()))
+        }
+    }
+}
+
+impl Interpolator {
+    /// Serialises the interpolator to JSON, the knots and the coefficients are stored as they are,
+    /// so the curve reads back exactly without being refitted
+    pub fn to_json(&self) -> String {
+        let gaps = self
+            .gaps
+            .iter()
+            .map(|&(start, end)| JsonValue::from_slice(&[start, end]))
+            .collect();
+        JsonValue::Object(vec![
+            (
+                String::from("format"),
+                JsonValue::String(String::from(FORMAT)),
+            ),
+            (String::from("version"), JsonValue::Number(VERSION as f64)),
+            (
+                String::from("interpolation_type"),
+                JsonValue::String(self.interpolation_type.to_string()),
+            ),
+            (
+                String::from("extrapolation"),
+                JsonValue::String(self.extrap_strategy.to_string()),
+            ),
+            (String::from("x"), JsonValue::from_slice(&self.x_values)),
+            (String::from("y"), JsonValue::from_slice(&self.y_values)),
+            (String::from("b"), JsonValue::from_slice(&self.b_coeffs)),
+            (String::from("c"), JsonValue::from_slice(&self.c_coeffs)),
+            (String::from("d"), JsonValue::from_slice(&self.d_coeffs)),
+            (String::from("gaps"), JsonValue::Array(gaps)),
+        ])
+        .to_string()
+    }
+
+    /// Reads an interpolator written by `to_json`
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let document = JsonValue::parse(json)?;
+        document.check_header(FORMAT, VERSION)?;
+        let interpolation_type: InterpolationType =
+            document.get("interpolation_type")?.as_str()?.parse()?;
+        let extrap_strategy: ExtrapolationStrategy =
+            document.get("extrapolation")?.as_str()?.parse()?;
+        let x_values = document.get("x")?.as_f64_vec()?;
+        let y_values = document.get("y")?.as_f64_vec()?;
+        let b_coeffs = document.get("b")?.as_f64_vec()?;
+        let c_coeffs = document.get("c")?.as_f64_vec()?;
+        let d_coeffs = document.get("d")?.as_f64_vec()?;
+        let gaps = document
+            .get("gaps")?
+            .as_array()?
+            .iter()
+            .map(|gap| match gap.as_f64_vec()?.as_slice() {
+                &[start, end] => Ok((start, end)),
+                _ => Err(String::from("A gap must have a start and an end")),
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        if x_values.len() != y_values.len() || x_values.len() < 2 {
+            return Err(String::from(
+                "x and y must have the same length and contain at least two points",
+            ));
+        }
+        if x_values.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(String::from("x must be strictly increasing"));
+        }
+        // Every segment must have its coefficients, the spline under tension keeps one value per knot
+        let segments = x_values.len() - 1;
+        let complete = match interpolation_type {
+            InterpolationType::ConstantBackward
+            | InterpolationType::ConstantForward
+            | InterpolationType::Sinc(_) => true,
+            InterpolationType::Tension(_) => c_coeffs.len() == x_values.len(),
+            _ => [&b_coeffs, &c_coeffs, &d_coeffs]
+                .iter()
+                .all(|coeffs| coeffs.len() >= segments),
+        };
+        interpolation_type.validate(&x_values, &y_values)?;
+        if !complete {
+            return Err(format!(
+                "Coefficients don't match {} interpolation on {} knots",
+                interpolation_type,
+                x_values.len()
+            ));
+        }
+
+        Ok(Self {
+            x_values,
+            y_values,
+            b_coeffs,
+            c_coeffs,
+            d_coeffs,
+            interpolation_type,
+            extrap_strategy,
+            gaps,
+        })
+    }
+}
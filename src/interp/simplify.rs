@@ -0,0 +1,129 @@
+use super::{ExtrapolationStrategy, InterpolationType, Interpolator};
+
+/// Picks a small subset of the points such that the interpolant of the given type through it stays within
+/// the tolerance of every original point, the first and the last points are always kept.
+/// Linear interpolation uses Douglas-Peucker with the vertical distance, the other types insert the worst
+/// approximated point until the tolerance is met and then greedily remove the knots that are no longer needed.
+/// Only the dropped points are checked, as the steps and the quadratic spline don't reproduce all of their knots.
+pub fn simplify(
+    x_values: &[f64],
+    y_values: &[f64],
+    interpolation_type: InterpolationType,
+    tolerance: f64,
+) -> Result<(Vec<f64>, Vec<f64>), String> {
+    if x_values.len() != y_values.len() || x_values.len() < 2 {
+        return Err(String::from(
+            "x_values and y_values must have the same length and contain at least two points",
+        ));
+    }
+    if x_values.iter().chain(y_values).any(|v| !v.is_finite()) {
+        return Err(String::from("x_values and y_values must be finite"));
+    }
+    if x_values.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(String::from("x_values must be strictly increasing"));
+    }
+    if tolerance.is_nan() || tolerance < 0.0 {
+        return Err(String::from("Tolerance must be non-negative"));
+    }
+    if let InterpolationType::Sinc(_) = interpolation_type {
+        return Err(String::from(
+            "Sinc interpolation requires equally spaced knots, a subset of them can't be used",
+        ));
+    }
+    interpolation_type.validate(x_values, y_values)?;
+
+    let kept = match interpolation_type {
+        InterpolationType::Linear => douglas_peucker(x_values, y_values, tolerance),
+        _ => {
+            let simplification = Simplification {
+                x_values,
+                y_values,
+                interpolation_type,
+            };
+            let kept = simplification.refine(tolerance);
+            simplification.prune(kept, tolerance)
+        }
+    };
+    Ok((
+        kept.iter().map(|&i| x_values[i]).collect(),
+        kept.iter().map(|&i| y_values[i]).collect(),
+    ))
+}
+
+/// Splits the chord between two kept points at the farthest point in between until every point is within tolerance
+fn douglas_peucker(x: &[f64], y: &[f64], tolerance: f64) -> Vec<usize> {
+    let mut kept = vec![0];
+    let mut stack = vec![(0, x.len() - 1)];
+    while let Some((start, end)) = stack.pop() {
+        let slope = (y[end] - y[start]) / (x[end] - x[start]);
+        let farthest = (start + 1..end)
+            .map(|i| (i, (y[i] - y[start] - slope * (x[i] - x[start])).abs()))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        match farthest {
+            // The right half goes on top of the stack last, so the points are kept from left to right
+            Some((i, distance)) if distance > tolerance => {
+                stack.push((i, end));
+                stack.push((start, i));
+            }
+            _ => kept.push(end),
+        }
+    }
+    kept
+}
+
+struct Simplification<'a> {
+    x_values: &'a [f64],
+    y_values: &'a [f64],
+    interpolation_type: InterpolationType,
+}
+
+impl Simplification<'_> {
+    /// Returns the largest error of the interpolant through the kept points over the dropped points,
+    /// and the point where it's reached, or None when every point is kept
+    fn worst_point(&self, kept: &[usize]) -> Option<(usize, f64)> {
+        let interpolator = Interpolator::new(
+            kept.iter().map(|&i| self.x_values[i]).collect(),
+            kept.iter().map(|&i| self.y_values[i]).collect(),
+            self.interpolation_type,
+            ExtrapolationStrategy::None,
+        );
+        (0..self.x_values.len())
+            .filter(|i| kept.binary_search(i).is_err())
+            .map(|i| {
+                let error = (interpolator.interpolate(self.x_values[i]) - self.y_values[i]).abs();
+                (i, error)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Starts from the end points and inserts the worst approximated point until the tolerance is met
+    /// or every point is kept
+    fn refine(&self, tolerance: f64) -> Vec<usize> {
+        let mut kept = vec![0, self.x_values.len() - 1];
+        while let Some((worst, error)) = self.worst_point(&kept) {
+            if error <= tolerance {
+                break;
+            }
+            let position = kept.binary_search(&worst).unwrap_err();
+            kept.insert(position, worst);
+        }
+        kept
+    }
+
+    /// Removes the knots one at a time from left to right whenever the interpolant stays within tolerance without them.
+    /// Non-local splines change everywhere when a knot is inserted, so some of the early knots are often redundant.
+    fn prune(&self, mut kept: Vec<usize>, tolerance: f64) -> Vec<usize> {
+        let mut position = 1;
+        while position + 1 < kept.len() {
+            let knot = kept.remove(position);
+            if self
+                .worst_point(&kept)
+                .is_some_and(|(_, error)| error > tolerance)
+            {
+                kept.insert(position, knot);
+                position += 1;
+            }
+        }
+        kept
+    }
+}
@@ -0,0 +1,166 @@
+use super::Interpolator;
+use std::f64::consts::PI;
+
+// Relative deviation of the knot spacing from the mean spacing that still counts as a uniform grid
+const UNIFORM_TOLERANCE: f64 = 1e-9;
+
+/// Enum to define the window tapering the sinc kernel of band-limited interpolation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SincWindow {
+    Lanczos(usize), // Lanczos window sinc(u / a), the kernel spans a samples on each side
+    Kaiser {
+        half_width: usize, // Number of samples on each side of the kernel
+        beta: f64,         // Shape parameter, larger values trade resolution for smaller ripples
+    }, // Kaiser window I0(beta * sqrt(1 - (u / a)^2)) / I0(beta)
+}
+
+impl SincWindow {
+    /// Returns the number of samples on each side of the kernel
+    pub fn half_width(&self) -> usize {
+        match *self {
+            SincWindow::Lanczos(a) => a,
+            SincWindow::Kaiser { half_width, .. } => half_width,
+        }
+    }
+
+    pub(super) fn validate(&self) -> Result<(), String> {
+        if self.half_width() == 0 {
+            return Err(String::from(
+                "The sinc window must span at least one sample",
+            ));
+        }
+        if let SincWindow::Kaiser { beta, .. } = *self {
+            if !(beta >= 0.0 && beta.is_finite()) {
+                return Err(format!("Kaiser beta must be non-negative, got {}", beta));
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates the windowed sinc kernel at the offset u, in samples
+    fn kernel(&self, u: f64) -> f64 {
+        let a = self.half_width() as f64;
+        if u.abs() >= a {
+            return 0.0;
+        }
+        let window = match *self {
+            SincWindow::Lanczos(_) => sinc(u / a),
+            SincWindow::Kaiser { beta, .. } => {
+                bessel_i0(beta * (1.0 - (u / a).powi(2)).sqrt()) / bessel_i0(beta)
+            }
+        };
+        sinc(u) * window
+    }
+
+    /// Computes the weights of the 2a taps from floor(t) - a + 1 to floor(t) + a for a position t with the given
+    /// fractional part. The weights are normalised to sum to one, so constant signals are reproduced exactly.
+    fn taps(&self, fraction: f64) -> Vec<f64> {
+        let a = self.half_width() as isize;
+        if fraction == 0.0 {
+            // On a sample the kernel vanishes at every other sample, sin(pi * k) just doesn't round to zero
+            return (1 - a..=a).map(|k| f64::from(k == 0)).collect();
+        }
+        let weights: Vec<f64> = (1 - a..=a)
+            .map(|k| self.kernel(fraction - k as f64))
+            .collect();
+        let sum: f64 = weights.iter().sum();
+        weights.iter().map(|w| w / sum).collect()
+    }
+}
+
+fn sinc(u: f64) -> f64 {
+    if u == 0.0 {
+        1.0
+    } else {
+        (PI * u).sin() / (PI * u)
+    }
+}
+
+/// Modified Bessel function of the first kind of order zero, summed from its power series
+fn bessel_i0(x: f64) -> f64 {
+    let quarter_square = x * x / 4.0;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut k = 1.0;
+    while term > f64::EPSILON * sum {
+        term *= quarter_square / (k * k);
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
+
+/// Checks that the knots are equally spaced
+pub(super) fn check_uniform(x: &[f64]) -> Result<(), String> {
+    let n = x.len() - 1;
+    let spacing = (x[n] - x[0]) / n as f64;
+    if x.windows(2)
+        .any(|w| ((w[1] - w[0]) - spacing).abs() > UNIFORM_TOLERANCE * spacing)
+    {
+        return Err(String::from(
+            "Sinc interpolation requires equally spaced x_values",
+        ));
+    }
+    Ok(())
+}
+
+/// Resamples a uniformly sampled signal by the rational factor up / down with windowed sinc interpolation,
+/// output sample m lies at m * down / up input samples from the first one. The position only takes up different
+/// fractional parts, so the kernel weights are computed once per phase and reused (polyphase filtering).
+/// When downsampling the kernel is not widened, so the signal has to be band-limited below the new Nyquist rate.
+pub fn resample_rational(
+    values: &[f64],
+    up: usize,
+    down: usize,
+    window: SincWindow,
+) -> Result<Vec<f64>, String> {
+    if values.is_empty() {
+        return Err(String::from("At least one value is required"));
+    }
+    if up == 0 || down == 0 {
+        return Err(String::from("Resampling factors must be positive"));
+    }
+    window.validate()?;
+
+    let n = values.len();
+    let phases: Vec<Vec<f64>> = (0..up).map(|p| window.taps(p as f64 / up as f64)).collect();
+    let a = window.half_width() as isize;
+    let length = (n - 1) * up / down + 1;
+    Ok((0..length)
+        .map(|m| {
+            let position = m * down;
+            let base = (position / up) as isize;
+            phases[position % up]
+                .iter()
+                .zip(1 - a..=a)
+                .map(|(w, k)| w * values[(base + k).clamp(0, n as isize - 1) as usize])
+                .sum()
+        })
+        .collect())
+}
+
+impl Interpolator {
+    /// Returns the node indices and the weights of the sinc kernel at x,
+    /// samples beyond the ends of the data repeat the boundary values
+    pub(super) fn sinc_taps(&self, x: f64, window: &SincWindow) -> Vec<(usize, f64)> {
+        let n = self.x_values.len();
+        let spacing = (self.x_values[n - 1] - self.x_values[0]) / (n - 1) as f64;
+        let t = (x - self.x_values[0]) / spacing;
+        let base = t.floor();
+        let a = window.half_width() as isize;
+        window
+            .taps(t - base)
+            .into_iter()
+            .zip(1 - a..=a)
+            .map(|(w, k)| ((base as isize + k).clamp(0, n as isize - 1) as usize, w))
+            .collect()
+    }
+
+    /// Evaluates the Whittaker-Shannon interpolation with the windowed sinc kernel at x
+    pub(super) fn evaluate_sinc(&self, x: f64, window: &SincWindow) -> f64 {
+        self.sinc_taps(x, window)
+            .iter()
+            .map(|&(i, w)| w * self.y_values[i])
+            .sum()
+    }
+}
@@ -0,0 +1,116 @@
+use super::Interpolator;
+use crate::linalg::solve_tridiagonal;
+
+// Exponential spline under tension, on the segment [x_j, x_j+1] with t = (x - x_j) / h it reads
+// s = y_j * (1 - t) + y_j+1 * t + z_j * G(1 - t) + z_j+1 * G(t), G(t) = h^2 / sigma^2 * (sinh(sigma * t) / sinh(sigma) - t)
+// where z are the second derivatives at the knots. Below this tension the series expansion of G is used
+// to avoid the cancellation between the two terms. An infinite tension is the limit G = 0, i.e. the broken line.
+const SMALL_TENSION: f64 = 1e-3;
+
+/// Solves for the second derivatives of the natural spline under tension at every knot
+pub(super) fn tension_second_derivatives(x: &[f64], y: &[f64], sigma: f64) -> Vec<f64> {
+    let n = x.len() - 1; // Number of segments
+    let h: Vec<f64> = (0..n).map(|i| x[i + 1] - x[i]).collect();
+    let slopes: Vec<f64> = (0..n).map(|i| (y[i + 1] - y[i]) / h[i]).collect();
+    let (off_diagonal, diagonal) = tension_system(&h, sigma);
+
+    let mut z = vec![0.0; n + 1];
+    if n > 1 && sigma.is_finite() {
+        let lower = &off_diagonal[1..n - 1];
+        let main: Vec<f64> = (1..n).map(|i| diagonal[i - 1] + diagonal[i]).collect();
+        let rhs: Vec<f64> = (1..n).map(|i| slopes[i] - slopes[i - 1]).collect();
+        let interior = solve_tridiagonal(lower, &main, lower, &rhs);
+        z[1..n].copy_from_slice(&interior);
+    }
+    z
+}
+
+/// Computes the per segment contributions e_j and f_j to the tridiagonal system of the second derivatives,
+/// continuity of the slope at x_i reads e_i-1 * z_i-1 + (f_i-1 + f_i) * z_i + e_i * z_i+1 = s_i - s_i-1
+pub(super) fn tension_system(h: &[f64], sigma: f64) -> (Vec<f64>, Vec<f64>) {
+    let (e, f) = if sigma < SMALL_TENSION {
+        let s2 = sigma * sigma;
+        (1.0 / 6.0 - 7.0 * s2 / 360.0, 1.0 / 3.0 - s2 / 45.0)
+    } else {
+        // 1 / sinh(sigma) and coth(sigma) written to stay finite for large tensions
+        let expm1 = (-2.0 * sigma).exp_m1();
+        let inverse_sinh = -2.0 * (-sigma).exp() / expm1;
+        let coth = -(2.0 + expm1) / expm1;
+        (
+            1.0 / sigma * (1.0 / sigma - inverse_sinh),
+            1.0 / sigma * (coth - 1.0 / sigma),
+        )
+    };
+    (
+        h.iter().map(|h| e * h).collect(),
+        h.iter().map(|h| f * h).collect(),
+    )
+}
+
+/// Evaluates the basis function G(t) of a segment of width h
+pub(super) fn tension_basis(sigma: f64, h: f64, t: f64) -> f64 {
+    if sigma.is_infinite() {
+        return 0.0;
+    }
+    if sigma < SMALL_TENSION {
+        let t2 = t * t;
+        return h
+            * h
+            * t
+            * ((t2 - 1.0) / 6.0 + sigma * sigma * (t2 * t2 / 120.0 - t2 / 36.0 + 7.0 / 360.0));
+    }
+    h * h / (sigma * sigma) * (sinh_ratio(sigma, t) - t)
+}
+
+/// Evaluates sinh(sigma * t) / sinh(sigma), which is G''(t) / h^2 and tends to t for vanishing tension
+pub(super) fn sinh_ratio(sigma: f64, t: f64) -> f64 {
+    if sigma < SMALL_TENSION {
+        return t;
+    }
+    if sigma.is_infinite() {
+        return if t >= 1.0 { 1.0 } else { 0.0 };
+    }
+    // exp(sigma * (t - 1)) * (1 - exp(-2 * sigma * t)) / (1 - exp(-2 * sigma)) stays finite for large tensions
+    (sigma * (t - 1.0)).exp() * (-2.0 * sigma * t).exp_m1() / (-2.0 * sigma).exp_m1()
+}
+
+/// Evaluates the derivative dG/dt of the basis function of a segment of width h
+pub(super) fn tension_basis_derivative(sigma: f64, h: f64, t: f64) -> f64 {
+    if sigma.is_infinite() {
+        return 0.0;
+    }
+    if sigma < SMALL_TENSION {
+        let t2 = t * t;
+        return h
+            * h
+            * ((3.0 * t2 - 1.0) / 6.0
+                + sigma * sigma * (t2 * t2 / 24.0 - t2 / 12.0 + 7.0 / 360.0));
+    }
+    // sigma * cosh(sigma * t) / sinh(sigma)
+    let ratio = -sigma * (sigma * (t - 1.0)).exp() * (1.0 + (-2.0 * sigma * t).exp())
+        / (-2.0 * sigma).exp_m1();
+    h * h / (sigma * sigma) * (ratio - 1.0)
+}
+
+impl Interpolator {
+    /// Evaluates the spline under tension on segment j at x, the second derivatives are stored in c_coeffs
+    pub(super) fn evaluate_tension(&self, j: usize, x: f64, sigma: f64) -> f64 {
+        let h = self.x_values[j + 1] - self.x_values[j];
+        let t = (x - self.x_values[j]) / h;
+        self.y_values[j] * (1.0 - t)
+            + self.y_values[j + 1] * t
+            + self.c_coeffs[j] * tension_basis(sigma, h, 1.0 - t)
+            + self.c_coeffs[j + 1] * tension_basis(sigma, h, t)
+    }
+
+    /// Evaluates the first derivative of the spline under tension on segment j at x
+    pub(super) fn tension_derivative(&self, j: usize, x: f64, sigma: f64) -> f64 {
+        let h = self.x_values[j + 1] - self.x_values[j];
+        let t = (x - self.x_values[j]) / h;
+        (self.y_values[j + 1]
+            - self.y_values[j]
+            - self.c_coeffs[j] * tension_basis_derivative(sigma, h, 1.0 - t)
+            + self.c_coeffs[j + 1] * tension_basis_derivative(sigma, h, t))
+            / h
+    }
+}
@@ -0,0 +1,164 @@
+use crate::linalg::LuDecomposition;
+
+/// Thin-plate spline surface f(x, y) = a0 + a1 * x + a2 * y + sum(w_i * phi(|p - p_i|)), phi(r) = r^2 * ln(r).
+/// With a positive smoothing parameter the surface doesn't pass through the data but minimises
+/// the squared residuals plus the smoothing parameter times the bending energy.
+#[derive(Debug, Clone)]
+pub struct ThinPlateSpline {
+    centers: Vec<(f64, f64)>,
+    weights: Vec<f64>,
+    affine: [f64; 3],
+    smoothing: f64,
+}
+
+impl ThinPlateSpline {
+    /// Fits a thin-plate spline to scattered points, a smoothing of zero gives an interpolating surface
+    pub fn new(points: &[(f64, f64)], values: &[f64], smoothing: f64) -> Result<Self, String> {
+        let solver = TpsSolver::new(points, values, smoothing)?;
+        Ok(solver.fit(points, values))
+    }
+
+    /// Fits a thin-plate spline choosing the smoothing parameter among the candidates by leave-one-out cross-validation
+    pub fn cross_validated(
+        points: &[(f64, f64)],
+        values: &[f64],
+        candidates: &[f64],
+    ) -> Result<Self, String> {
+        let mut best: Option<(f64, Self)> = None;
+        for &smoothing in candidates {
+            let solver = TpsSolver::new(points, values, smoothing)?;
+            let score = solver.leave_one_out_error(values);
+            if best.as_ref().is_none_or(|(s, _)| score < *s) {
+                best = Some((score, solver.fit(points, values)));
+            }
+        }
+        best.map(|(_, spline)| spline)
+            .ok_or_else(|| String::from("At least one smoothing candidate is required"))
+    }
+
+    /// Computes the mean squared leave-one-out residual for the given smoothing parameter
+    pub fn leave_one_out_error(
+        points: &[(f64, f64)],
+        values: &[f64],
+        smoothing: f64,
+    ) -> Result<f64, String> {
+        Ok(TpsSolver::new(points, values, smoothing)?.leave_one_out_error(values))
+    }
+
+    /// Evaluates the surface at (x, y)
+    pub fn evaluate(&self, x: f64, y: f64) -> f64 {
+        let [a0, a1, a2] = self.affine;
+        a0 + a1 * x
+            + a2 * y
+            + self
+                .centers
+                .iter()
+                .zip(&self.weights)
+                .map(|(&(cx, cy), w)| w * kernel((x - cx).hypot(y - cy)))
+                .sum::<f64>()
+    }
+
+    /// Evaluates the gradient (df/dx, df/dy) at (x, y)
+    pub fn gradient(&self, x: f64, y: f64) -> (f64, f64) {
+        let mut gradient = (self.affine[1], self.affine[2]);
+        for (&(cx, cy), w) in self.centers.iter().zip(&self.weights) {
+            let r = (x - cx).hypot(y - cy);
+            if r > 0.0 {
+                // d/dx (r^2 * ln(r)) = (x - cx) * (2 * ln(r) + 1)
+                let factor = w * (2.0 * r.ln() + 1.0);
+                gradient.0 += factor * (x - cx);
+                gradient.1 += factor * (y - cy);
+            }
+        }
+        gradient
+    }
+
+    /// Returns the smoothing parameter of the fit
+    pub fn smoothing(&self) -> f64 {
+        self.smoothing
+    }
+
+    /// Returns the affine part of the surface as [a0, a1, a2]
+    pub fn affine(&self) -> [f64; 3] {
+        self.affine
+    }
+}
+
+/// Radial basis function of the thin-plate spline
+fn kernel(r: f64) -> f64 {
+    if r == 0.0 {
+        0.0
+    } else {
+        r * r * r.ln()
+    }
+}
+
+/// Factorised saddle point system [[K + smoothing * I, P], [P^T, 0]] of the fit
+struct TpsSolver {
+    decomposition: LuDecomposition,
+    smoothing: f64,
+    n: usize,
+}
+
+impl TpsSolver {
+    fn new(points: &[(f64, f64)], values: &[f64], smoothing: f64) -> Result<Self, String> {
+        let n = points.len();
+        if values.len() != n {
+            return Err(String::from("points and values must have the same length"));
+        }
+        if n < 3 {
+            return Err(String::from("At least three points are required"));
+        }
+        if smoothing < 0.0 || !smoothing.is_finite() {
+            return Err(String::from("Smoothing must be non-negative"));
+        }
+
+        let mut system = vec![vec![0.0; n + 3]; n + 3];
+        for (i, &(xi, yi)) in points.iter().enumerate() {
+            for (j, &(xj, yj)) in points.iter().enumerate() {
+                system[i][j] = kernel((xi - xj).hypot(yi - yj));
+            }
+            system[i][i] += smoothing;
+            for (k, p) in [1.0, xi, yi].into_iter().enumerate() {
+                system[i][n + k] = p;
+                system[n + k][i] = p;
+            }
+        }
+        let decomposition = LuDecomposition::new(&system).map_err(|_| {
+            String::from("Thin-plate spline system is singular, the points must not be collinear")
+        })?;
+        Ok(Self {
+            decomposition,
+            smoothing,
+            n,
+        })
+    }
+
+    fn fit(&self, points: &[(f64, f64)], values: &[f64]) -> ThinPlateSpline {
+        let mut rhs = values.to_vec();
+        rhs.extend([0.0; 3]);
+        let solution = self.decomposition.solve(&rhs);
+        ThinPlateSpline {
+            centers: points.to_vec(),
+            weights: solution[..self.n].to_vec(),
+            affine: [solution[self.n], solution[self.n + 1], solution[self.n + 2]],
+            smoothing: self.smoothing,
+        }
+    }
+
+    /// The leave-one-out residual of point i is w_i / (M^-1)_ii (Rippa's rule), this holds for any smoothing
+    fn leave_one_out_error(&self, values: &[f64]) -> f64 {
+        let mut rhs = values.to_vec();
+        rhs.extend([0.0; 3]);
+        let weights = self.decomposition.solve(&rhs);
+        (0..self.n)
+            .map(|i| {
+                let mut unit = vec![0.0; self.n + 3];
+                unit[i] = 1.0;
+                let residual = weights[i] / self.decomposition.solve(&unit)[i];
+                residual * residual
+            })
+            .sum::<f64>()
+            / self.n as f64
+    }
+}
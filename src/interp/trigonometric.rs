@@ -0,0 +1,180 @@
+use super::{ExtrapolationStrategy, InterpolationType, Interpolator};
+use std::f64::consts::PI;
+
+/// Trigonometric interpolant of equally spaced samples of a periodic function,
+/// f(x) = a_0 + sum(a_k * cos(k * w * (x - x_0)) + b_k * sin(k * w * (x - x_0))) with w = 2 * pi / period.
+/// For an even number of samples the highest frequency only keeps its cosine term.
+#[derive(Debug, Clone)]
+pub struct TrigonometricInterpolator {
+    start: f64,
+    period: f64,
+    cosine_coeffs: Vec<f64>,
+    sine_coeffs: Vec<f64>,
+}
+
+impl TrigonometricInterpolator {
+    /// Builds the interpolant of the values sampled at start + i * period / n, i = 0..n,
+    /// the sample at start + period is the first one again and must not be repeated
+    pub fn new(start: f64, period: f64, values: &[f64]) -> Result<Self, String> {
+        if values.is_empty() {
+            return Err(String::from("At least one sample is required"));
+        }
+        if period <= 0.0 || !period.is_finite() {
+            return Err(String::from("Period must be positive and finite"));
+        }
+        let n = values.len();
+        let mut cosine_coeffs = vec![0.0; n / 2 + 1];
+        let mut sine_coeffs = vec![0.0; n / 2 + 1];
+        for k in 0..=n / 2 {
+            // The constant and the Nyquist terms are not doubled
+            let scale = if k == 0 || 2 * k == n { 1.0 } else { 2.0 } / n as f64;
+            for (j, &y) in values.iter().enumerate() {
+                let angle = 2.0 * PI * ((j * k) % n) as f64 / n as f64;
+                cosine_coeffs[k] += scale * y * angle.cos();
+                sine_coeffs[k] += scale * y * angle.sin();
+            }
+        }
+        if n.is_multiple_of(2) {
+            sine_coeffs[n / 2] = 0.0;
+        }
+        sine_coeffs[0] = 0.0;
+        Ok(Self {
+            start,
+            period,
+            cosine_coeffs,
+            sine_coeffs,
+        })
+    }
+
+    /// Evaluates the interpolant at x, any x is valid as the interpolant is periodic
+    pub fn interpolate(&self, x: f64) -> f64 {
+        self.evaluate(&self.cosine_coeffs, &self.sine_coeffs, x)
+    }
+
+    /// Evaluates the derivative of the given order at x
+    pub fn derivative(&self, x: f64, order: u32) -> f64 {
+        let mut cosine_coeffs = self.cosine_coeffs.clone();
+        let mut sine_coeffs = self.sine_coeffs.clone();
+        for _ in 0..order {
+            // d/dx (a * cos(kwx) + b * sin(kwx)) = kw * b * cos(kwx) - kw * a * sin(kwx)
+            for k in 0..cosine_coeffs.len() {
+                let frequency = k as f64 * self.frequency();
+                let (a, b) = (cosine_coeffs[k], sine_coeffs[k]);
+                cosine_coeffs[k] = frequency * b;
+                sine_coeffs[k] = -frequency * a;
+            }
+        }
+        self.evaluate(&cosine_coeffs, &sine_coeffs, x)
+    }
+
+    /// Computes the exact integral of the interpolant between the bounds,
+    /// over any full period it equals the mean times the period
+    pub fn integral(&self, lower: f64, upper: f64) -> f64 {
+        let antiderivative = |x: f64| {
+            let t = x - self.start;
+            let periodic: f64 = (1..self.cosine_coeffs.len())
+                .map(|k| {
+                    let frequency = k as f64 * self.frequency();
+                    (self.cosine_coeffs[k] * (frequency * t).sin()
+                        - self.sine_coeffs[k] * (frequency * t).cos())
+                        / frequency
+                })
+                .sum();
+            self.mean() * t + periodic
+        };
+        antiderivative(upper) - antiderivative(lower)
+    }
+
+    /// Returns the mean of the interpolant over a period
+    pub fn mean(&self) -> f64 {
+        self.cosine_coeffs[0]
+    }
+
+    /// Returns the period of the interpolant
+    pub fn period(&self) -> f64 {
+        self.period
+    }
+
+    /// Returns the Fourier coefficients as (a_k, b_k) for k = 0..=n/2
+    pub fn coefficients(&self) -> (&[f64], &[f64]) {
+        (&self.cosine_coeffs, &self.sine_coeffs)
+    }
+
+    fn frequency(&self) -> f64 {
+        2.0 * PI / self.period
+    }
+
+    /// Sums the trigonometric series, cos and sin of the multiples are generated by rotation
+    fn evaluate(&self, cosine_coeffs: &[f64], sine_coeffs: &[f64], x: f64) -> f64 {
+        // Reducing to a single period first keeps the rotation accurate for large x
+        let t = (x - self.start).rem_euclid(self.period);
+        let (sin, cos) = (self.frequency() * t).sin_cos();
+        let (mut sin_k, mut cos_k) = (0.0, 1.0);
+        let mut sum = cosine_coeffs[0];
+        for k in 1..cosine_coeffs.len() {
+            (sin_k, cos_k) = (sin_k * cos + cos_k * sin, cos_k * cos - sin_k * sin);
+            sum += cosine_coeffs[k] * cos_k + sine_coeffs[k] * sin_k;
+        }
+        sum
+    }
+}
+
+/// Interpolator of angles in radians, the angles are unwrapped before interpolating so that
+/// consecutive points take the shortest way around the circle, e.g. from 3.0 to -3.0 passes through pi
+#[derive(Debug)]
+pub struct AngularInterpolator {
+    interpolator: Interpolator,
+}
+
+impl AngularInterpolator {
+    /// Creates a new AngularInterpolator with the given angles
+    pub fn new(
+        x_values: Vec<f64>,
+        angles: Vec<f64>,
+        interpolation_type: InterpolationType,
+        extrap_strategy: ExtrapolationStrategy,
+    ) -> Self {
+        Self {
+            interpolator: Interpolator::new(
+                x_values,
+                unwrap_angles(&angles),
+                interpolation_type,
+                extrap_strategy,
+            ),
+        }
+    }
+
+    /// Interpolates the angle at x, the result lies in (-pi, pi]
+    pub fn interpolate(&self, x: f64) -> f64 {
+        wrap_angle(self.interpolator.interpolate(x))
+    }
+
+    /// Interpolates the continuous angle at x, without wrapping it back to (-pi, pi]
+    pub fn interpolate_unwrapped(&self, x: f64) -> f64 {
+        self.interpolator.interpolate(x)
+    }
+}
+
+/// Adds multiples of 2 * pi so that consecutive angles differ by at most pi
+fn unwrap_angles(angles: &[f64]) -> Vec<f64> {
+    let mut unwrapped = Vec::with_capacity(angles.len());
+    for (i, &angle) in angles.iter().enumerate() {
+        if i == 0 {
+            unwrapped.push(angle);
+        } else {
+            let previous: f64 = unwrapped[i - 1];
+            unwrapped.push(previous + wrap_angle(angle - previous));
+        }
+    }
+    unwrapped
+}
+
+/// Maps an angle to (-pi, pi]
+fn wrap_angle(angle: f64) -> f64 {
+    let wrapped = (angle + PI).rem_euclid(2.0 * PI) - PI;
+    if wrapped == -PI {
+        PI
+    } else {
+        wrapped
+    }
+}
@@ -0,0 +1,374 @@
+// Minimal JSON reader and writer used to persist the library objects without external dependencies.
+// Numbers are f64, the non-finite values that JSON can't represent are written as the strings "NaN", "Infinity" and "-Infinity".
+use std::fmt;
+
+// Deepest nesting of arrays and objects accepted by the parser, which recurses once per level
+const MAX_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Parses a JSON document, the whole input must be a single value
+    pub(crate) fn parse(input: &str) -> Result<JsonValue, String> {
+        let mut parser = Parser {
+            bytes: input.as_bytes(),
+            position: 0,
+            depth: 0,
+        };
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.position != parser.bytes.len() {
+            return Err(parser.error("Unexpected trailing characters"));
+        }
+        Ok(value)
+    }
+
+    /// Builds a number, non-finite values are stored as strings
+    pub(crate) fn from_f64(value: f64) -> JsonValue {
+        if value.is_nan() {
+            JsonValue::String(String::from("NaN"))
+        } else if value.is_infinite() {
+            JsonValue::String(String::from(if value > 0.0 {
+                "Infinity"
+            } else {
+                "-Infinity"
+            }))
+        } else {
+            JsonValue::Number(value)
+        }
+    }
+
+    pub(crate) fn from_slice(values: &[f64]) -> JsonValue {
+        JsonValue::Array(values.iter().map(|&v| JsonValue::from_f64(v)).collect())
+    }
+
+    /// Returns the member of an object
+    pub(crate) fn get(&self, key: &str) -> Result<&JsonValue, String> {
+        match self {
+            JsonValue::Object(members) => members
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value)
+                .ok_or_else(|| format!("Missing field \"{}\"", key)),
+            _ => Err(format!("Expected an object with the field \"{}\"", key)),
+        }
+    }
+
+    pub(crate) fn as_f64(&self) -> Result<f64, String> {
+        match self {
+            JsonValue::Number(value) => Ok(*value),
+            JsonValue::String(text) => match text.as_str() {
+                "NaN" => Ok(f64::NAN),
+                "Infinity" => Ok(f64::INFINITY),
+                "-Infinity" => Ok(f64::NEG_INFINITY),
+                _ => Err(format!("Expected a number, got \"{}\"", text)),
+            },
+            _ => Err(String::from("Expected a number")),
+        }
+    }
+
+    pub(crate) fn as_usize(&self) -> Result<usize, String> {
+        match self {
+            JsonValue::Number(value) if *value >= 0.0 && value.fract() == 0.0 => {
+                Ok(*value as usize)
+            }
+            _ => Err(String::from("Expected a non-negative integer")),
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Result<&str, String> {
+        match self {
+            JsonValue::String(text) => Ok(text),
+            _ => Err(String::from("Expected a string")),
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Result<&[JsonValue], String> {
+        match self {
+            JsonValue::Array(values) => Ok(values),
+            _ => Err(String::from("Expected an array")),
+        }
+    }
+
+    pub(crate) fn as_f64_vec(&self) -> Result<Vec<f64>, String> {
+        self.as_array()?.iter().map(JsonValue::as_f64).collect()
+    }
+
+    /// Checks the format name and version header of a persisted object
+    pub(crate) fn check_header(&self, format: &str, version: usize) -> Result<(), String> {
+        let found = self.get("format")?.as_str()?;
+        if found != format {
+            return Err(format!(
+                "Expected the format \"{}\", got \"{}\"",
+                format, found
+            ));
+        }
+        let found = self.get("version")?.as_usize()?;
+        if found != version {
+            return Err(format!(
+                "Unsupported {} version {}, expected {}",
+                format, found, version
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonValue::Null => write!(f, "null"),
+            JsonValue::Bool(value) => write!(f, "{}", value),
+            // Both forms print the shortest representation that reads back to the same value
+            JsonValue::Number(value) if *value == 0.0 || (1e-5..1e16).contains(&value.abs()) => {
+                write!(f, "{}", value)
+            }
+            JsonValue::Number(value) => write!(f, "{:e}", value),
+            JsonValue::String(text) => write_string(f, text),
+            JsonValue::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+            JsonValue::Object(members) => {
+                write!(f, "{{")?;
+                for (i, (name, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, name)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in text.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> String {
+        format!("{} at position {}", message, self.position)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .bytes
+            .get(self.position)
+            .is_some_and(|b| b.is_ascii_whitespace())
+        {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.bytes.get(self.position) == Some(&byte) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{}'", byte as char)))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, String> {
+        self.skip_whitespace();
+        match self.bytes.get(self.position) {
+            Some(b'{') => self.nested(Self::parse_object),
+            Some(b'[') => self.nested(Self::parse_array),
+            Some(b'"') => self.parse_string().map(JsonValue::String),
+            Some(b't') => self.parse_literal("true", JsonValue::Bool(true)),
+            Some(b'f') => self.parse_literal("false", JsonValue::Bool(false)),
+            Some(b'n') => self.parse_literal("null", JsonValue::Null),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            _ => Err(self.error("Expected a value")),
+        }
+    }
+
+    /// Parses an array or object one level deeper, failing beyond MAX_DEPTH instead of overflowing the stack
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<JsonValue, String>,
+    ) -> Result<JsonValue, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(&format!("Nesting deeper than {} levels", MAX_DEPTH)));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: JsonValue) -> Result<JsonValue, String> {
+        if self.bytes[self.position..].starts_with(literal.as_bytes()) {
+            self.position += literal.len();
+            Ok(value)
+        } else {
+            Err(self.error("Invalid literal"))
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue, String> {
+        let start = self.position;
+        while self
+            .bytes
+            .get(self.position)
+            .is_some_and(|b| matches!(b, b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9'))
+        {
+            self.position += 1;
+        }
+        let text = std::str::from_utf8(&self.bytes[start..self.position]).unwrap();
+        text.parse::<f64>()
+            .map(JsonValue::Number)
+            .map_err(|_| self.error(&format!("Invalid number \"{}\"", text)))
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let mut bytes = Vec::new();
+        loop {
+            let byte = *self
+                .bytes
+                .get(self.position)
+                .ok_or_else(|| self.error("Unterminated string"))?;
+            self.position += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escape = *self
+                        .bytes
+                        .get(self.position)
+                        .ok_or_else(|| self.error("Unterminated string"))?;
+                    self.position += 1;
+                    let unescaped = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.parse_unicode_escape()?,
+                        _ => return Err(self.error("Invalid escape sequence")),
+                    };
+                    let mut buffer = [0; 4];
+                    bytes.extend_from_slice(unescaped.encode_utf8(&mut buffer).as_bytes());
+                }
+                _ => bytes.push(byte),
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| self.error("Invalid UTF-8 in string"))
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<char, String> {
+        let code = self.parse_hex()?;
+        // Characters outside of the basic plane are written as a surrogate pair
+        let code = if (0xD800..0xDC00).contains(&code) {
+            if !self.bytes[self.position..].starts_with(b"\\u") {
+                return Err(self.error("Unpaired surrogate"));
+            }
+            self.position += 2;
+            let low = self.parse_hex()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.error("Unpaired surrogate"));
+            }
+            0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            code
+        };
+        char::from_u32(code).ok_or_else(|| self.error("Invalid unicode escape"))
+    }
+
+    fn parse_hex(&mut self) -> Result<u32, String> {
+        let digits = self
+            .bytes
+            .get(self.position..self.position + 4)
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| self.error("Invalid unicode escape"))?;
+        self.position += 4;
+        Ok(digits)
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, String> {
+        self.expect(b'[')?;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.bytes.get(self.position) == Some(&b']') {
+            self.position += 1;
+            return Ok(JsonValue::Array(values));
+        }
+        loop {
+            values.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.bytes.get(self.position) {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(JsonValue::Array(values));
+                }
+                _ => return Err(self.error("Expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, String> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.bytes.get(self.position) == Some(&b'}') {
+            self.position += 1;
+            return Ok(JsonValue::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let name = self.parse_string()?;
+            self.expect(b':')?;
+            members.push((name, self.parse_value()?));
+            self.skip_whitespace();
+            match self.bytes.get(self.position) {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(JsonValue::Object(members));
+                }
+                _ => return Err(self.error("Expected ',' or '}'")),
+            }
+        }
+    }
+}
@@ -1,2 +1,6 @@
+pub mod approximation;
 pub mod interp;
+mod json;
+mod linalg;
+pub mod regression;
 pub mod root_finding;
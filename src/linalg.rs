@@ -0,0 +1,433 @@
+// Small dense linear algebra toolkit used internally, matrices are stored as vectors of rows
+
+/// Computes the lower triangular Cholesky factor L of a symmetric positive definite matrix, A = L * L^T
+pub(crate) fn cholesky(a: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, String> {
+    let n = a.len();
+    let mut l = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+            if i == j {
+                let diagonal = a[i][i] - sum;
+                if diagonal <= 0.0 || !diagonal.is_finite() {
+                    return Err(String::from("Matrix is not positive definite"));
+                }
+                l[i][j] = diagonal.sqrt();
+            } else {
+                l[i][j] = (a[i][j] - sum) / l[j][j];
+            }
+        }
+    }
+    Ok(l)
+}
+
+/// Solves A * x = b given the Cholesky factor L of A
+pub(crate) fn cholesky_solve(l: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n = l.len();
+    let z = forward_substitution(l, b);
+    // Backward substitution L^T * x = z
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        let sum: f64 = (i + 1..n).map(|k| l[k][i] * x[k]).sum();
+        x[i] = (z[i] - sum) / l[i][i];
+    }
+    x
+}
+
+/// Solves L * z = b for a lower triangular L
+pub(crate) fn forward_substitution(l: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n = l.len();
+    let mut z = vec![0.0; n];
+    for i in 0..n {
+        let sum: f64 = (0..i).map(|k| l[i][k] * z[k]).sum();
+        z[i] = (b[i] - sum) / l[i][i];
+    }
+    z
+}
+
+/// Computes the inverse of A given the Cholesky factor L of A
+pub(crate) fn cholesky_inverse(l: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = l.len();
+    let mut inverse = vec![vec![0.0; n]; n];
+    for j in 0..n {
+        let mut unit = vec![0.0; n];
+        unit[j] = 1.0;
+        let column = cholesky_solve(l, &unit);
+        for i in 0..n {
+            inverse[i][j] = column[i];
+        }
+    }
+    inverse
+}
+
+/// Solves a tridiagonal system using the Thomas algorithm.
+/// `lower` and `upper` hold the sub- and super-diagonals and are one element shorter than `diagonal`
+pub(crate) fn solve_tridiagonal(
+    lower: &[f64],
+    diagonal: &[f64],
+    upper: &[f64],
+    rhs: &[f64],
+) -> Vec<f64> {
+    let n = diagonal.len();
+    if n == 0 {
+        return vec![];
+    }
+    let mut c_prime = vec![0.0; n];
+    let mut d_prime = vec![0.0; n];
+    c_prime[0] = if n > 1 { upper[0] / diagonal[0] } else { 0.0 };
+    d_prime[0] = rhs[0] / diagonal[0];
+    for i in 1..n {
+        let denominator = diagonal[i] - lower[i - 1] * c_prime[i - 1];
+        if i < n - 1 {
+            c_prime[i] = upper[i] / denominator;
+        }
+        d_prime[i] = (rhs[i] - lower[i - 1] * d_prime[i - 1]) / denominator;
+    }
+    let mut x = vec![0.0; n];
+    x[n - 1] = d_prime[n - 1];
+    for i in (0..n - 1).rev() {
+        x[i] = d_prime[i] - c_prime[i] * x[i + 1];
+    }
+    x
+}
+
+/// Computes all eigenvalues of a real square matrix as (real, imaginary) pairs.
+/// The matrix is balanced, reduced to upper Hessenberg form and then iterated with the Francis double shift QR
+pub(crate) fn eigenvalues(matrix: &[Vec<f64>]) -> Result<Vec<(f64, f64)>, String> {
+    let mut a = matrix.to_vec();
+    balance(&mut a);
+    reduce_to_hessenberg(&mut a);
+    hessenberg_qr(&mut a)
+}
+
+/// Rescales rows and columns by powers of two to make their norms comparable, this doesn't change eigenvalues
+#[allow(clippy::needless_range_loop)] // Row i and column i are scaled together
+fn balance(a: &mut [Vec<f64>]) {
+    const RADIX: f64 = 2.0;
+    let n = a.len();
+    let mut done = false;
+    while !done {
+        done = true;
+        for i in 0..n {
+            let mut c: f64 = (0..n).filter(|&j| j != i).map(|j| a[j][i].abs()).sum();
+            let r: f64 = (0..n).filter(|&j| j != i).map(|j| a[i][j].abs()).sum();
+            if c == 0.0 || r == 0.0 {
+                continue;
+            }
+            let s = c + r;
+            let mut f = 1.0;
+            while c < r / RADIX {
+                f *= RADIX;
+                c *= RADIX * RADIX;
+            }
+            while c > r * RADIX {
+                f /= RADIX;
+                c /= RADIX * RADIX;
+            }
+            if (c + r) / f < 0.95 * s {
+                done = false;
+                for j in 0..n {
+                    a[i][j] /= f;
+                    a[j][i] *= f;
+                }
+            }
+        }
+    }
+}
+
+/// Reduces a matrix to upper Hessenberg form by Gaussian elimination with pivoting
+#[allow(clippy::needless_range_loop)] // Rows and columns are addressed together, as in the textbook elimination
+fn reduce_to_hessenberg(a: &mut [Vec<f64>]) {
+    let n = a.len();
+    for m in 1..n.saturating_sub(1) {
+        let mut pivot = 0.0;
+        let mut i = m;
+        for j in m..n {
+            if a[j][m - 1].abs() > f64::abs(pivot) {
+                pivot = a[j][m - 1];
+                i = j;
+            }
+        }
+        if i != m {
+            a.swap(i, m);
+            for row in a.iter_mut() {
+                row.swap(i, m);
+            }
+        }
+        if pivot != 0.0 {
+            for i in m + 1..n {
+                let y = a[i][m - 1] / pivot;
+                if y != 0.0 {
+                    for j in m - 1..n {
+                        a[i][j] -= y * a[m][j];
+                    }
+                    for row in a.iter_mut() {
+                        row[m] += y * row[i];
+                    }
+                }
+            }
+        }
+    }
+    for (i, row) in a.iter_mut().enumerate() {
+        for value in row.iter_mut().take(i.saturating_sub(1)) {
+            *value = 0.0;
+        }
+    }
+}
+
+/// Finds the eigenvalues of an upper Hessenberg matrix with the shifted QR algorithm, the matrix is destroyed
+#[allow(clippy::needless_range_loop)] // The Householder reflections update up to three rows at once
+fn hessenberg_qr(a: &mut [Vec<f64>]) -> Result<Vec<(f64, f64)>, String> {
+    let n = a.len();
+    let mut eigenvalues = vec![(0.0, 0.0); n];
+    let norm: f64 = (0..n)
+        .flat_map(|i| (i.saturating_sub(1)..n).map(move |j| (i, j)))
+        .map(|(i, j)| a[i][j].abs())
+        .sum();
+    let mut nn = n as isize - 1;
+    let mut t = 0.0; // Accumulated exceptional shifts
+    while nn >= 0 {
+        let mut iterations = 0;
+        loop {
+            let last = nn as usize;
+            // Look for a single small subdiagonal element to split the matrix
+            let mut l = last;
+            while l > 0 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == 0.0 {
+                    s = norm;
+                }
+                if a[l][l - 1].abs() <= f64::EPSILON * s {
+                    a[l][l - 1] = 0.0;
+                    break;
+                }
+                l -= 1;
+            }
+            let mut x = a[last][last];
+            if l == last {
+                // One root found
+                eigenvalues[last] = (x + t, 0.0);
+                nn -= 1;
+                break;
+            }
+            let mut y = a[last - 1][last - 1];
+            let mut w = a[last][last - 1] * a[last - 1][last];
+            if l == last - 1 {
+                // Two roots found
+                let p = 0.5 * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x += t;
+                if q >= 0.0 {
+                    let z = p + z.copysign(p);
+                    eigenvalues[last - 1] = (x + z, 0.0);
+                    eigenvalues[last] = (if z != 0.0 { x - w / z } else { x + z }, 0.0);
+                } else {
+                    eigenvalues[last - 1] = (x + p, z);
+                    eigenvalues[last] = (x + p, -z);
+                }
+                nn -= 2;
+                break;
+            }
+
+            if iterations == 60 {
+                return Err(String::from("Eigenvalue iteration failed to converge"));
+            }
+            if iterations == 10 || iterations == 20 {
+                // Exceptional shift
+                t += x;
+                for (i, row) in a.iter_mut().enumerate().take(last + 1) {
+                    row[i] -= x;
+                }
+                let s = a[last][last - 1].abs() + a[last - 1][last - 2].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            iterations += 1;
+
+            // Form the shift and look for two consecutive small subdiagonal elements
+            let mut m = last - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = a[m][m];
+                let r0 = x - z;
+                let s0 = y - z;
+                p = (r0 * s0 - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - r0 - s0;
+                r = a[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                if u <= f64::EPSILON * v {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in m + 2..=last {
+                a[i][i - 2] = 0.0;
+                if i != m + 2 {
+                    a[i][i - 3] = 0.0;
+                }
+            }
+
+            // Double QR step on rows l to last and columns m to last
+            for k in m..last {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = if k != last - 1 { a[k + 2][k - 1] } else { 0.0 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != 0.0 {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+                let s = (p * p + q * q + r * r).sqrt().copysign(p);
+                if s == 0.0 {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        a[k][k - 1] = -a[k][k - 1];
+                    }
+                } else {
+                    a[k][k - 1] = -s * x;
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q /= p;
+                r /= p;
+                for j in k..=last {
+                    let mut p = a[k][j] + q * a[k + 1][j];
+                    if k != last - 1 {
+                        p += r * a[k + 2][j];
+                        a[k + 2][j] -= p * z;
+                    }
+                    a[k + 1][j] -= p * y;
+                    a[k][j] -= p * x;
+                }
+                let mmin = if last < k + 3 { last } else { k + 3 };
+                for row in a.iter_mut().take(mmin + 1).skip(l) {
+                    let mut p = x * row[k] + y * row[k + 1];
+                    if k != last - 1 {
+                        p += z * row[k + 2];
+                        row[k + 2] -= p * r;
+                    }
+                    row[k + 1] -= p * q;
+                    row[k] -= p;
+                }
+            }
+        }
+    }
+    Ok(eigenvalues)
+}
+
+/// LU decomposition with partial pivoting, the factors are stored in a single matrix along with the row permutation
+pub(crate) struct LuDecomposition {
+    lu: Vec<Vec<f64>>,
+    permutation: Vec<usize>,
+}
+
+impl LuDecomposition {
+    /// Factorises the matrix, fails if the matrix is numerically singular
+    #[allow(clippy::needless_range_loop)] // The pivot row updates the rows below it
+    pub(crate) fn new(a: &[Vec<f64>]) -> Result<Self, String> {
+        let n = a.len();
+        let mut lu = a.to_vec();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let norm = lu.iter().flatten().fold(0.0, |m: f64, v| m.max(v.abs()));
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&i, &j| lu[i][k].abs().total_cmp(&lu[j][k].abs()))
+                .unwrap();
+            if lu[pivot][k].abs() <= f64::EPSILON * norm * n as f64 {
+                return Err(String::from("Matrix is singular"));
+            }
+            lu.swap(k, pivot);
+            permutation.swap(k, pivot);
+            for i in k + 1..n {
+                lu[i][k] /= lu[k][k];
+                let factor = lu[i][k];
+                for j in k + 1..n {
+                    lu[i][j] -= factor * lu[k][j];
+                }
+            }
+        }
+        Ok(Self { lu, permutation })
+    }
+
+    /// Solves A * x = b
+    pub(crate) fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.lu.len();
+        let mut x: Vec<f64> = self.permutation.iter().map(|&i| b[i]).collect();
+        for i in 0..n {
+            for k in 0..i {
+                x[i] -= self.lu[i][k] * x[k];
+            }
+        }
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                x[i] -= self.lu[i][k] * x[k];
+            }
+            x[i] /= self.lu[i][i];
+        }
+        x
+    }
+}
+
+/// Computes the singular values and the right singular vectors of an m x n matrix with the one-sided Jacobi method.
+/// Pairs of columns are rotated until they are all orthogonal, the singular values are then the norms of the columns
+/// and the accumulated rotations give V. The singular values are not sorted, column j of V goes with the j-th value.
+pub(crate) fn singular_value_decomposition(a: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let m = a.len();
+    let n = if m == 0 { 0 } else { a[0].len() };
+    let mut u = a.to_vec();
+    let mut v = vec![vec![0.0; n]; n];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+    for _ in 0..60 {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                for row in &u {
+                    alpha += row[p] * row[p];
+                    beta += row[q] * row[q];
+                    gamma += row[p] * row[q];
+                }
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() || gamma == 0.0 {
+                    continue;
+                }
+                rotated = true;
+                // Rotation that zeroes the off-diagonal entry of the 2 x 2 Gram matrix of the columns
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                let s = c * t;
+                for row in u.iter_mut().chain(v.iter_mut()) {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+    let singular_values = (0..n)
+        .map(|j| (0..m).map(|i| u[i][j] * u[i][j]).sum::<f64>().sqrt())
+        .collect();
+    (singular_values, v)
+}
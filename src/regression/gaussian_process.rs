@@ -0,0 +1,354 @@
+use crate::linalg::{cholesky, cholesky_solve, forward_substitution};
+use std::f64::consts::PI;
+
+// Lower bound of the noise variance relative to the variance of the data, keeps the covariance matrix factorisable
+const MIN_RELATIVE_NOISE: f64 = 1e-10;
+
+/// Enum to define the covariance function of a Gaussian process, r is the distance between two points
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kernel {
+    SquaredExponential {
+        variance: f64,
+        length_scale: f64,
+    }, // variance * exp(-r^2 / (2 * l^2)), infinitely smooth
+    Matern32 {
+        variance: f64,
+        length_scale: f64,
+    }, // Matérn 3/2, once differentiable sample paths
+    Matern52 {
+        variance: f64,
+        length_scale: f64,
+    }, // Matérn 5/2, twice differentiable sample paths
+    Periodic {
+        variance: f64,
+        length_scale: f64,
+        period: f64,
+    }, // variance * exp(-2 * sin^2(pi * r / period) / l^2), the period is not fitted
+}
+
+impl Kernel {
+    /// Evaluates the covariance between the values at x and y
+    pub fn evaluate(&self, x: f64, y: f64) -> f64 {
+        let r = (x - y).abs();
+        match *self {
+            Kernel::SquaredExponential {
+                variance,
+                length_scale,
+            } => variance * (-0.5 * (r / length_scale).powi(2)).exp(),
+            Kernel::Matern32 {
+                variance,
+                length_scale,
+            } => {
+                let s = 3f64.sqrt() * r / length_scale;
+                variance * (1.0 + s) * (-s).exp()
+            }
+            Kernel::Matern52 {
+                variance,
+                length_scale,
+            } => {
+                let s = 5f64.sqrt() * r / length_scale;
+                variance * (1.0 + s + s * s / 3.0) * (-s).exp()
+            }
+            Kernel::Periodic {
+                variance,
+                length_scale,
+                period,
+            } => {
+                let sine = (PI * r / period).sin();
+                variance * (-2.0 * (sine / length_scale).powi(2)).exp()
+            }
+        }
+    }
+
+    /// Returns the variance of the values, i.e. the covariance at distance zero
+    pub fn variance(&self) -> f64 {
+        match *self {
+            Kernel::SquaredExponential { variance, .. }
+            | Kernel::Matern32 { variance, .. }
+            | Kernel::Matern52 { variance, .. }
+            | Kernel::Periodic { variance, .. } => variance,
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        let positive = |v: f64| v > 0.0 && v.is_finite();
+        match *self {
+            Kernel::SquaredExponential {
+                variance,
+                length_scale,
+            }
+            | Kernel::Matern32 {
+                variance,
+                length_scale,
+            }
+            | Kernel::Matern52 {
+                variance,
+                length_scale,
+            } => positive(variance) && positive(length_scale),
+            Kernel::Periodic {
+                variance,
+                length_scale,
+                period,
+            } => positive(variance) && positive(length_scale) && positive(period),
+        }
+    }
+
+    /// Returns the logarithms of the fitted hyperparameters, the variance and the length scale
+    fn log_parameters(&self) -> [f64; 2] {
+        match *self {
+            Kernel::SquaredExponential {
+                variance,
+                length_scale,
+            }
+            | Kernel::Matern32 {
+                variance,
+                length_scale,
+            }
+            | Kernel::Matern52 {
+                variance,
+                length_scale,
+            }
+            | Kernel::Periodic {
+                variance,
+                length_scale,
+                ..
+            } => [variance.ln(), length_scale.ln()],
+        }
+    }
+
+    fn with_log_parameters(&self, parameters: [f64; 2]) -> Kernel {
+        let (variance, length_scale) = (parameters[0].exp(), parameters[1].exp());
+        match *self {
+            Kernel::SquaredExponential { .. } => Kernel::SquaredExponential {
+                variance,
+                length_scale,
+            },
+            Kernel::Matern32 { .. } => Kernel::Matern32 {
+                variance,
+                length_scale,
+            },
+            Kernel::Matern52 { .. } => Kernel::Matern52 {
+                variance,
+                length_scale,
+            },
+            Kernel::Periodic { period, .. } => Kernel::Periodic {
+                variance,
+                length_scale,
+                period,
+            },
+        }
+    }
+}
+
+/// Gaussian process regression of y = f(x) + noise with a constant mean equal to the mean of the data.
+/// The prediction returns the posterior mean and variance of f, the noise variance is added for a new observation.
+#[derive(Debug, Clone)]
+pub struct GaussianProcess {
+    kernel: Kernel,
+    noise_variance: f64,
+    x_values: Vec<f64>,
+    mean: f64,
+    cholesky: Vec<Vec<f64>>,
+    alpha: Vec<f64>,
+    log_marginal_likelihood: f64,
+}
+
+impl GaussianProcess {
+    /// Conditions the Gaussian process with the given kernel and noise variance on the data
+    pub fn new(
+        x_values: &[f64],
+        y_values: &[f64],
+        kernel: Kernel,
+        noise_variance: f64,
+    ) -> Result<Self, String> {
+        if x_values.len() != y_values.len() || x_values.is_empty() {
+            return Err(String::from(
+                "x_values and y_values must have the same length and contain at least one point",
+            ));
+        }
+        if x_values.iter().chain(y_values).any(|v| !v.is_finite()) {
+            return Err(String::from("x_values and y_values must be finite"));
+        }
+        if !kernel.is_valid() {
+            return Err(String::from(
+                "Kernel parameters must be positive and finite",
+            ));
+        }
+        if !(noise_variance >= 0.0 && noise_variance.is_finite()) {
+            return Err(String::from("Noise variance must be non-negative"));
+        }
+
+        let n = x_values.len();
+        let mean = y_values.iter().sum::<f64>() / n as f64;
+        let centred: Vec<f64> = y_values.iter().map(|y| y - mean).collect();
+        let covariance: Vec<Vec<f64>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| {
+                        kernel.evaluate(x_values[i], x_values[j])
+                            + if i == j { noise_variance } else { 0.0 }
+                    })
+                    .collect()
+            })
+            .collect();
+        let l = cholesky(&covariance).map_err(|_| {
+            String::from("Covariance matrix is not positive definite, increase the noise variance")
+        })?;
+        let alpha = cholesky_solve(&l, &centred);
+        // log p(y) = -y^T K^-1 y / 2 - log|K| / 2 - n log(2 pi) / 2 with log|K| = 2 sum(log L_ii)
+        let log_marginal_likelihood = -0.5
+            * centred.iter().zip(&alpha).map(|(y, a)| y * a).sum::<f64>()
+            - (0..n).map(|i| l[i][i].ln()).sum::<f64>()
+            - 0.5 * n as f64 * (2.0 * PI).ln();
+        Ok(Self {
+            kernel,
+            noise_variance,
+            x_values: x_values.to_vec(),
+            mean,
+            cholesky: l,
+            alpha,
+            log_marginal_likelihood,
+        })
+    }
+
+    /// Fits the variance and the length scale of the kernel and the noise variance by maximising the
+    /// log marginal likelihood with the Nelder-Mead method, starting from the given values
+    pub fn fit(
+        x_values: &[f64],
+        y_values: &[f64],
+        initial_kernel: Kernel,
+        initial_noise_variance: f64,
+    ) -> Result<Self, String> {
+        let initial = Self::new(x_values, y_values, initial_kernel, initial_noise_variance)?;
+        let n = y_values.len() as f64;
+        let data_variance = y_values
+            .iter()
+            .map(|y| (y - initial.mean).powi(2))
+            .sum::<f64>()
+            / n;
+        let noise_floor = MIN_RELATIVE_NOISE * data_variance.max(f64::MIN_POSITIVE);
+
+        // Parameters are optimised in log space, which keeps them positive
+        let [log_variance, log_length_scale] = initial_kernel.log_parameters();
+        let start = [
+            log_variance,
+            log_length_scale,
+            initial_noise_variance.max(noise_floor).ln(),
+        ];
+        let build = |p: &[f64; 3]| {
+            Self::new(
+                x_values,
+                y_values,
+                initial_kernel.with_log_parameters([p[0], p[1]]),
+                p[2].exp().max(noise_floor),
+            )
+        };
+        let best = nelder_mead(
+            |p| build(p).map_or(f64::INFINITY, |gp| -gp.log_marginal_likelihood),
+            start,
+        );
+        let fitted = build(&best)?;
+        // The search can only improve on the starting point
+        Ok(
+            if fitted.log_marginal_likelihood >= initial.log_marginal_likelihood {
+                fitted
+            } else {
+                initial
+            },
+        )
+    }
+
+    /// Returns the posterior mean and variance of the function at x
+    pub fn predict(&self, x: f64) -> (f64, f64) {
+        let covariances: Vec<f64> = self
+            .x_values
+            .iter()
+            .map(|&xi| self.kernel.evaluate(x, xi))
+            .collect();
+        let mean = self.mean
+            + covariances
+                .iter()
+                .zip(&self.alpha)
+                .map(|(k, a)| k * a)
+                .sum::<f64>();
+        let v = forward_substitution(&self.cholesky, &covariances);
+        let variance = self.kernel.evaluate(x, x) - v.iter().map(|v| v * v).sum::<f64>();
+        (mean, variance.max(0.0))
+    }
+
+    /// Returns the kernel of the process
+    pub fn kernel(&self) -> Kernel {
+        self.kernel
+    }
+
+    /// Returns the variance of the observation noise
+    pub fn noise_variance(&self) -> f64 {
+        self.noise_variance
+    }
+
+    /// Returns the log marginal likelihood of the data
+    pub fn log_marginal_likelihood(&self) -> f64 {
+        self.log_marginal_likelihood
+    }
+}
+
+/// Minimises the function with the Nelder-Mead simplex method from the starting point
+fn nelder_mead<const N: usize>(function: impl Fn(&[f64; N]) -> f64, start: [f64; N]) -> [f64; N] {
+    let mut simplex: Vec<([f64; N], f64)> = (0..=N)
+        .map(|i| {
+            let mut point = start;
+            if i > 0 {
+                point[i - 1] += 1.0;
+            }
+            (point, function(&point))
+        })
+        .collect();
+    for _ in 0..200 * N {
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let (best, worst) = (simplex[0].1, simplex[N].1);
+        if (worst - best).abs() <= 1e-10 * (1.0 + best.abs()) {
+            break;
+        }
+        let centroid: [f64; N] =
+            std::array::from_fn(|k| simplex[..N].iter().map(|(p, _)| p[k]).sum::<f64>() / N as f64);
+        let along = |t: f64| -> [f64; N] {
+            std::array::from_fn(|k| centroid[k] + t * (simplex[N].0[k] - centroid[k]))
+        };
+
+        let reflected = along(-1.0);
+        let reflected_value = function(&reflected);
+        if reflected_value < best {
+            let expanded = along(-2.0);
+            let expanded_value = function(&expanded);
+            simplex[N] = if expanded_value < reflected_value {
+                (expanded, expanded_value)
+            } else {
+                (reflected, reflected_value)
+            };
+        } else if reflected_value < simplex[N - 1].1 {
+            simplex[N] = (reflected, reflected_value);
+        } else {
+            let contracted = if reflected_value < worst {
+                along(-0.5)
+            } else {
+                along(0.5)
+            };
+            let contracted_value = function(&contracted);
+            if contracted_value < worst.min(reflected_value) {
+                simplex[N] = (contracted, contracted_value);
+            } else {
+                // Shrink the simplex towards the best point
+                let best_point = simplex[0].0;
+                for (point, value) in simplex.iter_mut().skip(1) {
+                    *point = std::array::from_fn(|k| (point[k] + best_point[k]) / 2.0);
+                    *value = function(point);
+                }
+            }
+        }
+    }
+    simplex
+        .into_iter()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap()
+        .0
+}
@@ -0,0 +1,89 @@
+use crate::interp::{ExtrapolationStrategy, InterpolationType, Interpolator};
+
+/// Enum to define the direction of an isotonic fit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Monotonicity {
+    Increasing, // Fitted values never decrease
+    Decreasing, // Fitted values never increase
+}
+
+/// Fits the monotone sequence closest to the values in weighted least squares, with the pool-adjacent-violators
+/// algorithm in O(n). The values are taken in the given order, adjacent values that violate the monotonicity are
+/// pooled into blocks sharing their weighted mean.
+pub fn isotonic_regression(
+    y_values: &[f64],
+    weights: Option<&[f64]>,
+    monotonicity: Monotonicity,
+) -> Result<Vec<f64>, String> {
+    let n = y_values.len();
+    let weights = match weights {
+        Some(w) if w.len() != n => {
+            return Err(String::from(
+                "weights must have the same length as y_values",
+            ))
+        }
+        Some(w) if w.iter().any(|&w| w <= 0.0 || !w.is_finite()) => {
+            return Err(String::from("weights must be positive and finite"))
+        }
+        Some(w) => w.to_vec(),
+        None => vec![1.0; n],
+    };
+    if y_values.iter().any(|y| !y.is_finite()) {
+        return Err(String::from("y_values must be finite"));
+    }
+    // A decreasing fit is the increasing fit of the negated values
+    let sign = match monotonicity {
+        Monotonicity::Increasing => 1.0,
+        Monotonicity::Decreasing => -1.0,
+    };
+
+    // Stack of blocks as (weighted mean, total weight, number of values)
+    let mut blocks: Vec<(f64, f64, usize)> = Vec::with_capacity(n);
+    for (&y, &w) in y_values.iter().zip(&weights) {
+        let mut block = (sign * y, w, 1);
+        while let Some(&(mean, weight, count)) = blocks.last() {
+            if mean < block.0 {
+                break;
+            }
+            blocks.pop();
+            let total = weight + block.1;
+            block = (
+                (mean * weight + block.0 * block.1) / total,
+                total,
+                count + block.2,
+            );
+        }
+        blocks.push(block);
+    }
+    Ok(blocks
+        .iter()
+        .flat_map(|&(mean, _, count)| std::iter::repeat_n(sign * mean, count))
+        .collect())
+}
+
+/// Fits the isotonic regression of the data ordered by x and interpolates the fitted values with a monotone cubic
+/// spline, so that the interpolant is monotone everywhere, e.g. for CDF-like curves free of arbitrage.
+/// The x values must be strictly increasing.
+pub fn isotonic_interpolator(
+    x_values: &[f64],
+    y_values: &[f64],
+    weights: Option<&[f64]>,
+    monotonicity: Monotonicity,
+    extrap_strategy: ExtrapolationStrategy,
+) -> Result<Interpolator, String> {
+    if x_values.len() != y_values.len() || x_values.len() < 2 {
+        return Err(String::from(
+            "x_values and y_values must have the same length and contain at least two points",
+        ));
+    }
+    if x_values.iter().any(|x| x.is_nan()) || x_values.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(String::from("x_values must be strictly increasing"));
+    }
+    let fitted = isotonic_regression(y_values, weights, monotonicity)?;
+    Ok(Interpolator::new(
+        x_values.to_vec(),
+        fitted,
+        InterpolationType::MonotoneCubic,
+        extrap_strategy,
+    ))
+}
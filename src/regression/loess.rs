@@ -0,0 +1,196 @@
+use crate::interp::{ExtrapolationStrategy, InterpolationType, Interpolator};
+use crate::linalg::{cholesky, cholesky_solve};
+
+/// LOESS (locally weighted polynomial regression) smoother with robustifying iterations.
+/// Each point is fitted by a weighted least-squares polynomial over the nearest span * n points with tricube
+/// weights, then the fits are repeated with bisquare weights on the residuals to discount outliers (LOWESS).
+#[derive(Debug, Clone)]
+pub struct Loess {
+    span: f64,
+    degree: usize,
+    robustness_iterations: usize,
+}
+
+impl Loess {
+    /// Creates a smoother with the usual defaults: span 2/3, local linear fits and 3 robustifying iterations
+    pub fn new() -> Self {
+        Self {
+            span: 2.0 / 3.0,
+            degree: 1,
+            robustness_iterations: 3,
+        }
+    }
+
+    /// Sets the fraction of the points used in each local fit, in (0, 1]
+    pub fn span(mut self, span: f64) -> Self {
+        self.span = span;
+        self
+    }
+
+    /// Sets the degree of the local polynomials, from 0 to 2
+    pub fn degree(mut self, degree: usize) -> Self {
+        self.degree = degree;
+        self
+    }
+
+    /// Sets the number of robustifying iterations, zero gives the plain weighted fit
+    pub fn robustness_iterations(mut self, iterations: usize) -> Self {
+        self.robustness_iterations = iterations;
+        self
+    }
+
+    /// Returns the smoothed values at the x values, which must be sorted in increasing order
+    pub fn fit(&self, x_values: &[f64], y_values: &[f64]) -> Result<Vec<f64>, String> {
+        let n = x_values.len();
+        if n != y_values.len() {
+            return Err(String::from(
+                "x_values and y_values must have the same length",
+            ));
+        }
+        if !(self.span > 0.0 && self.span <= 1.0) {
+            return Err(String::from("Span must be in (0, 1]"));
+        }
+        if self.degree > 2 {
+            return Err(String::from("Degree must be 0, 1 or 2"));
+        }
+        if n <= self.degree {
+            return Err(format!(
+                "At least {} points are required for local fits of degree {}",
+                self.degree + 1,
+                self.degree
+            ));
+        }
+        if x_values.windows(2).any(|w| w[0] > w[1]) || x_values.iter().any(|x| x.is_nan()) {
+            return Err(String::from("x_values must be sorted in increasing order"));
+        }
+
+        // Number of points in each neighbourhood, enough to fit the polynomial
+        let size = ((self.span * n as f64).ceil() as usize).clamp(self.degree + 1, n);
+        let mut robustness = vec![1.0; n];
+        let mut fitted = self.local_fits(x_values, y_values, size, &robustness);
+        for _ in 0..self.robustness_iterations {
+            let residuals: Vec<f64> = (0..n).map(|i| y_values[i] - fitted[i]).collect();
+            let scale = 6.0 * median(residuals.iter().map(|r| r.abs()).collect());
+            if scale == 0.0 {
+                break;
+            }
+            robustness = residuals.iter().map(|r| bisquare(r / scale)).collect();
+            fitted = self.local_fits(x_values, y_values, size, &robustness);
+        }
+        Ok(fitted)
+    }
+
+    /// Smooths the data and wraps the smoothed values in an Interpolator over the x values,
+    /// which must be strictly increasing
+    pub fn fit_interpolator(
+        &self,
+        x_values: &[f64],
+        y_values: &[f64],
+        interpolation_type: InterpolationType,
+        extrap_strategy: ExtrapolationStrategy,
+    ) -> Result<Interpolator, String> {
+        if x_values.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(String::from("x_values must be strictly increasing"));
+        }
+        if x_values.len() < 2 {
+            return Err(String::from("At least two points are required"));
+        }
+        let fitted = self.fit(x_values, y_values)?;
+        interpolation_type.validate(x_values, &fitted)?;
+        Ok(Interpolator::new(
+            x_values.to_vec(),
+            fitted,
+            interpolation_type,
+            extrap_strategy,
+        ))
+    }
+
+    fn local_fits(&self, x: &[f64], y: &[f64], size: usize, robustness: &[f64]) -> Vec<f64> {
+        let n = x.len();
+        // The neighbourhood is a window of consecutive points as x is sorted
+        let mut start = 0;
+        (0..n)
+            .map(|i| {
+                while start + size < n && x[i] - x[start] > x[start + size] - x[i] {
+                    start += 1;
+                }
+                let window = start..start + size;
+                let radius = (x[i] - x[start]).max(x[start + size - 1] - x[i]);
+                let weights: Vec<f64> = window
+                    .clone()
+                    .map(|j| tricube(x[j] - x[i], radius) * robustness[j])
+                    .collect();
+                self.local_polynomial(&x[window.clone()], &y[window], &weights, x[i], radius)
+            })
+            .collect()
+    }
+
+    /// Fits the weighted polynomial centred at x0 and returns its value at x0, lowering the degree
+    /// when the points don't determine the polynomial
+    fn local_polynomial(&self, x: &[f64], y: &[f64], weights: &[f64], x0: f64, radius: f64) -> f64 {
+        let scale = if radius > 0.0 { radius } else { 1.0 };
+        for degree in (0..=self.degree).rev() {
+            let terms = degree + 1;
+            let mut normal = vec![vec![0.0; terms]; terms];
+            let mut rhs = vec![0.0; terms];
+            for ((&xj, &yj), &w) in x.iter().zip(y).zip(weights) {
+                let t = (xj - x0) / scale;
+                let powers: Vec<f64> = (0..terms).map(|k| t.powi(k as i32)).collect();
+                for a in 0..terms {
+                    rhs[a] += w * powers[a] * yj;
+                    for b in 0..terms {
+                        normal[a][b] += w * powers[a] * powers[b];
+                    }
+                }
+            }
+            if let Ok(l) = cholesky(&normal) {
+                // Discard nearly singular systems, e.g. all the weight on a single x
+                let conditioning = (0..terms).map(|k| l[k][k]).fold(f64::INFINITY, f64::min)
+                    / (0..terms).map(|k| l[k][k]).fold(0.0, f64::max);
+                if conditioning > 1e-7 {
+                    return cholesky_solve(&l, &rhs)[0];
+                }
+            }
+        }
+        // No weight at all, the local fit falls back to the unweighted mean of the window
+        y.iter().sum::<f64>() / y.len() as f64
+    }
+}
+
+impl Default for Loess {
+    fn default() -> Self {
+        Loess::new()
+    }
+}
+
+/// Tricube weight (1 - |d / radius|^3)^3, every point gets full weight when the radius is zero
+fn tricube(distance: f64, radius: f64) -> f64 {
+    if radius == 0.0 {
+        return 1.0;
+    }
+    let u = (distance / radius).abs();
+    if u >= 1.0 {
+        0.0
+    } else {
+        (1.0 - u * u * u).powi(3)
+    }
+}
+
+/// Bisquare weight (1 - u^2)^2 for |u| < 1
+fn bisquare(u: f64) -> f64 {
+    if u.abs() >= 1.0 {
+        0.0
+    } else {
+        (1.0 - u * u).powi(2)
+    }
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(f64::total_cmp);
+    let n = values.len();
+    if n % 2 == 1 {
+        values[n / 2]
+    } else {
+        (values[n / 2 - 1] + values[n / 2]) / 2.0
+    }
+}
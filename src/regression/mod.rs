@@ -0,0 +1,7 @@
+mod gaussian_process;
+mod isotonic;
+mod loess;
+
+pub use gaussian_process::{GaussianProcess, Kernel};
+pub use isotonic::{isotonic_interpolator, isotonic_regression, Monotonicity};
+pub use loess::Loess;
@@ -1,3 +1,8 @@
+use crate::json::JsonValue;
+
+const FORMAT: &str = "numerics-rs/convergence_log";
+const VERSION: usize = 1;
+
 /// Represents a log to keep track of the convergence of a root-finding algorithm.
 pub struct ConvergenceLog {
     iterations: Vec<IterationEntry>,
@@ -64,6 +69,53 @@ impl ConvergenceLog {
     pub fn reset(&mut self) {
         self.iterations.clear();
     }
+
+    /// Serialises the convergence log to JSON.
+    pub fn to_json(&self) -> String {
+        let iterations = self
+            .iterations
+            .iter()
+            .map(|entry| {
+                JsonValue::Object(vec![
+                    (
+                        String::from("iteration"),
+                        JsonValue::Number(entry.iteration as f64),
+                    ),
+                    (String::from("x"), JsonValue::from_slice(&entry.x)),
+                    (String::from("fx"), JsonValue::from_slice(&entry.fx)),
+                ])
+            })
+            .collect();
+        JsonValue::Object(vec![
+            (
+                String::from("format"),
+                JsonValue::String(String::from(FORMAT)),
+            ),
+            (String::from("version"), JsonValue::Number(VERSION as f64)),
+            (String::from("iterations"), JsonValue::Array(iterations)),
+        ])
+        .to_string()
+    }
+
+    /// Reads a convergence log written by `to_json`.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let document = JsonValue::parse(json)?;
+        document.check_header(FORMAT, VERSION)?;
+        let mut log = ConvergenceLog::new();
+        for entry in document.get("iterations")?.as_array()? {
+            let x = entry.get("x")?.as_f64_vec()?;
+            let fx = entry.get("fx")?.as_f64_vec()?;
+            if x.len() != fx.len() {
+                return Err(String::from("x and fx vectors must have the same length"));
+            }
+            log.add_entry(
+                entry.get("iteration")?.as_usize()?,
+                x.into_boxed_slice(),
+                fx.into_boxed_slice(),
+            );
+        }
+        Ok(log)
+    }
 }
 
 impl Default for ConvergenceLog {
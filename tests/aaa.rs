@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::approximation::BarycentricRational;
+
+    fn grid(n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| -1.0 + 2.0 * i as f64 / (n - 1) as f64)
+            .collect()
+    }
+
+    #[test]
+    fn test_aaa_approximates_smooth_function() {
+        let points = grid(200);
+        let values: Vec<f64> = points.iter().map(|x| x.exp() * (3.0 * x).sin()).collect();
+        let rational = BarycentricRational::aaa(&points, &values, 1e-13, 100).unwrap();
+        assert!(rational.degree() < 20);
+        assert!(rational.error() < 1e-12);
+        for i in 0..=333 {
+            let x = -1.0 + 2.0 * i as f64 / 333.0;
+            assert!((rational.evaluate(x) - x.exp() * (3.0 * x).sin()).abs() < 1e-11);
+        }
+        // Support points are interpolated exactly
+        for (z, f) in rational.support_points().iter().zip(rational.values()) {
+            assert_eq!(rational.evaluate(*z), *f);
+        }
+    }
+
+    #[test]
+    fn test_aaa_recovers_rational_function() {
+        // f(x) = 1 / (x - 1.5) + 2 / (x + 1.5) = (3x - 1.5) / (x^2 - 2.25)
+        let points = grid(50);
+        let values: Vec<f64> = points
+            .iter()
+            .map(|x| 1.0 / (x - 1.5) + 2.0 / (x + 1.5))
+            .collect();
+        let rational = BarycentricRational::aaa(&points, &values, 1e-13, 100).unwrap();
+        assert_eq!(rational.degree(), 2);
+
+        let poles = rational.poles().unwrap();
+        let residues = rational.residues().unwrap();
+        assert_eq!(poles.len(), 2);
+        for ((pole, residue), (expected_pole, expected_residue)) in
+            poles.iter().zip(&residues).zip([(-1.5, 2.0), (1.5, 1.0)])
+        {
+            assert!((pole.0 - expected_pole).abs() < 1e-10 && pole.1.abs() < 1e-10);
+            assert!((residue.0 - expected_residue).abs() < 1e-8 && residue.1.abs() < 1e-8);
+        }
+        // The numerator has degree one, the other zero is at infinity
+        let zeros = rational.zeros().unwrap();
+        assert_eq!(zeros.len(), 1);
+        assert!((zeros[0].0 - 0.5).abs() < 1e-10 && zeros[0].1.abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_aaa_complex_poles() {
+        // Runge's function has poles at +-i/5, close to the interval
+        let points = grid(400);
+        let values: Vec<f64> = points.iter().map(|x| 1.0 / (1.0 + 25.0 * x * x)).collect();
+        let rational = BarycentricRational::aaa(&points, &values, 1e-13, 100).unwrap();
+        let poles = rational.poles().unwrap();
+        let residues = rational.residues().unwrap();
+        let mut found = 0;
+        for (pole, residue) in poles.iter().zip(&residues) {
+            if pole.0.abs() < 1e-8 && (pole.1.abs() - 0.2).abs() < 1e-8 {
+                // Residue of 1 / (25 (x - i/5)(x + i/5)) at +-i/5 is -+i/10
+                assert!(residue.0.abs() < 1e-6);
+                assert!((residue.1 + pole.1.signum() * 0.1).abs() < 1e-6);
+                found += 1;
+            }
+        }
+        assert_eq!(found, 2);
+    }
+
+    #[test]
+    fn test_aaa_invalid_input() {
+        assert!(BarycentricRational::aaa(&[0.0, 1.0], &[1.0], 1e-13, 10).is_err());
+        assert!(BarycentricRational::aaa(&[0.0, 0.0], &[1.0, 2.0], 1e-13, 10).is_err());
+        assert!(BarycentricRational::aaa(&[0.0, f64::NAN], &[1.0, 2.0], 1e-13, 10).is_err());
+        assert!(BarycentricRational::aaa(&[0.0, 1.0], &[1.0, 2.0], -1.0, 10).is_err());
+
+        // The degree is capped
+        let points = grid(100);
+        let values: Vec<f64> = points.iter().map(|x| x.abs()).collect();
+        let rational = BarycentricRational::aaa(&points, &values, 1e-13, 4).unwrap();
+        assert_eq!(rational.degree(), 4);
+        assert!(rational.error() > 1e-13);
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{
+        pdf_path, svg_path, BezierCurve, CatmullRomParameterization, ExtrapolationStrategy,
+        InterpolationType, Interpolator,
+    };
+    const EPSILON: f64 = 1e-12;
+
+    fn assert_close(a: (f64, f64), b: (f64, f64)) {
+        assert!(
+            (a.0 - b.0).abs() < EPSILON && (a.1 - b.1).abs() < EPSILON,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    fn cubic() -> BezierCurve {
+        BezierCurve::new(vec![(0.0, 0.0), (1.0, 2.0), (3.0, -1.0), (4.0, 1.0)]).unwrap()
+    }
+
+    #[test]
+    fn test_evaluate_and_subdivide() {
+        let curve = cubic();
+        assert_eq!(curve.degree(), 3);
+        assert_close(curve.evaluate(0.0), (0.0, 0.0));
+        assert_close(curve.evaluate(1.0), (4.0, 1.0));
+        // Bernstein form at t = 1/2: (P0 + 3 P1 + 3 P2 + P3) / 8
+        assert_close(curve.evaluate(0.5), (2.0, 0.5));
+
+        let (left, right) = curve.subdivide(0.3);
+        assert_eq!((left.degree(), right.degree()), (3, 3));
+        for i in 0..=10 {
+            let s = i as f64 / 10.0;
+            assert_close(left.evaluate(s), curve.evaluate(0.3 * s));
+            assert_close(right.evaluate(s), curve.evaluate(0.3 + 0.7 * s));
+        }
+        assert!(BezierCurve::new(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_elevate_and_bounding_box() {
+        let curve = cubic();
+        let elevated = curve.elevate().elevate();
+        assert_eq!(elevated.degree(), 5);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert_close(elevated.evaluate(t), curve.evaluate(t));
+        }
+
+        // y(t) = 9t(1 - t)(1 - 2t) has its extremes -+sqrt(3) / 2 inside the curve
+        let curve =
+            BezierCurve::new(vec![(0.0, 0.0), (1.0, 3.0), (3.0, -3.0), (4.0, 0.0)]).unwrap();
+        let ((x_min, y_min), (x_max, y_max)) = curve.bounding_box();
+        assert!(x_min.abs() < EPSILON && (x_max - 4.0).abs() < EPSILON);
+        assert!((y_max - 3f64.sqrt() / 2.0).abs() < EPSILON);
+        assert!((y_min + 3f64.sqrt() / 2.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_spline_segments() {
+        let x_values = vec![0.0, 1.0, 2.5, 3.0, 5.0];
+        let y_values = vec![0.0, 2.0, 1.0, 3.0, 2.0];
+        for (interpolation_type, degree) in [
+            (InterpolationType::Linear, 1),
+            (InterpolationType::ConvexityPreserving, 2),
+            (InterpolationType::Cubic, 3),
+            (
+                InterpolationType::Cardinal {
+                    tension: 0.0,
+                    parameterization: CatmullRomParameterization::Chordal,
+                },
+                3,
+            ),
+        ] {
+            let interpolator = Interpolator::new(
+                x_values.clone(),
+                y_values.clone(),
+                interpolation_type,
+                ExtrapolationStrategy::None,
+            );
+            let curves = interpolator.to_bezier().unwrap();
+            for curve in &curves {
+                assert_eq!(curve.degree(), degree);
+                for i in 0..=8 {
+                    let (x, y) = curve.evaluate(i as f64 / 8.0);
+                    assert!((interpolator.interpolate(x) - y).abs() < 1e-9);
+                }
+            }
+            // Consecutive segments join, so the path is a single subpath
+            assert_eq!(svg_path(&curves).unwrap().matches('M').count(), 1);
+        }
+        let tension = Interpolator::new(
+            x_values,
+            y_values,
+            InterpolationType::Tension(1.0),
+            ExtrapolationStrategy::None,
+        );
+        assert!(tension.to_bezier().is_err());
+    }
+
+    #[test]
+    fn test_path_commands() {
+        let quadratic = BezierCurve::new(vec![(0.0, 0.0), (1.0, 2.0), (2.0, 0.0)]).unwrap();
+        let line = BezierCurve::new(vec![(2.0, 0.0), (3.0, 0.5)]).unwrap();
+        assert_eq!(
+            svg_path(&[quadratic.clone(), line.clone(), cubic()]).unwrap(),
+            "M 0 0 Q 1 2 2 0 L 3 0.5 M 0 0 C 1 2 3 -1 4 1"
+        );
+        let pdf = pdf_path(&[quadratic, line]).unwrap();
+        assert!(pdf.starts_with("0 0 m ") && pdf.ends_with(" 2 0 c 3 0.5 l"));
+        assert!(svg_path(&[cubic().elevate()]).is_err());
+    }
+}
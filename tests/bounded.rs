@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{
+        cross_validate, simplify, ExtrapolationStrategy, InterpolationType, Interpolator,
+        ValidationMethod,
+    };
+
+    fn grid(start: f64, end: f64, count: usize) -> Vec<f64> {
+        (0..=count)
+            .map(|i| start + (end - start) * i as f64 / count as f64)
+            .collect()
+    }
+
+    #[test]
+    fn test_bounded_stays_positive() {
+        // A hazard rate curve collapsing to zero, the natural cubic spline overshoots below zero
+        let x = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let y = vec![5.0, 5.0, 5.0, 0.1, 0.0, 0.1, 5.0];
+        let cubic = Interpolator::new(
+            x.clone(),
+            y.clone(),
+            InterpolationType::Cubic,
+            ExtrapolationStrategy::None,
+        );
+        let bounded = Interpolator::new(
+            x.clone(),
+            y.clone(),
+            InterpolationType::Bounded {
+                lower: 0.0,
+                upper: f64::INFINITY,
+            },
+            ExtrapolationStrategy::Constant,
+        );
+        let points = grid(0.0, 6.0, 600);
+        assert!(points.iter().any(|&x| cubic.interpolate(x) < 0.0));
+        assert!(points.iter().all(|&x| bounded.interpolate(x) >= 0.0));
+        // The control points of every segment, and so the segments themselves, lie within the bounds
+        for curve in bounded.to_bezier().unwrap() {
+            assert!(curve.control_points().iter().all(|(_, y)| *y >= -1e-12));
+        }
+        for (xi, yi) in x.iter().zip(&y) {
+            assert!((bounded.interpolate(*xi) - yi).abs() < 1e-12);
+        }
+        assert_eq!(bounded.interpolate(10.0), 5.0);
+    }
+
+    #[test]
+    fn test_bounded_probabilities_are_smooth() {
+        let x = vec![0.0, 0.5, 1.0, 2.0, 3.0, 5.0];
+        let y = vec![0.0, 0.9, 1.0, 0.2, 1.0, 0.95];
+        let bounded = Interpolator::new(
+            x.clone(),
+            y,
+            InterpolationType::Bounded {
+                lower: 0.0,
+                upper: 1.0,
+            },
+            ExtrapolationStrategy::None,
+        );
+        assert!(grid(0.0, 5.0, 1000)
+            .iter()
+            .map(|&x| bounded.interpolate(x))
+            .all(|v| (0.0..=1.0).contains(&v)));
+        for curve in bounded.to_bezier().unwrap() {
+            let ((_, low), (_, high)) = curve.bounding_box();
+            assert!(low >= -1e-12 && high <= 1.0 + 1e-12);
+        }
+
+        // The slopes are limited but continuous at the interior knots
+        let h = 1e-7;
+        for &knot in &x[1..x.len() - 1] {
+            let left = (bounded.interpolate(knot) - bounded.interpolate(knot - h)) / h;
+            let right = (bounded.interpolate(knot + h) - bounded.interpolate(knot)) / h;
+            assert!((left - right).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_bounded_extend_spline() {
+        let x = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let y = vec![5.0, 5.0, 5.0, 0.1, 0.0, 0.1, 5.0];
+        let bounded = |lower: f64, upper: f64, y: Vec<f64>| {
+            Interpolator::new(
+                x.clone(),
+                y,
+                InterpolationType::Bounded { lower, upper },
+                ExtrapolationStrategy::ExtendSpline,
+            )
+        };
+        // The values are not clamped, the interior stays within the bounds on its own
+        let positive = bounded(0.0, f64::INFINITY, y.clone());
+        assert!(grid(-10.0, 20.0, 30000)
+            .iter()
+            .all(|&x| positive.interpolate(x) >= 0.0));
+        // Rising towards the infinite upper bound on both sides, the end segments are extended by their tangent lines
+        let h = 1e-7;
+        let left = (positive.interpolate(h) - positive.interpolate(0.0)) / h;
+        let right = (positive.interpolate(6.0) - positive.interpolate(6.0 - h)) / h;
+        assert!(left < 0.0 && right > 0.0);
+        assert!((positive.interpolate(-10.0) - (5.0 - 10.0 * left)).abs() < 1e-5);
+        assert!((positive.interpolate(16.0) - (5.0 + 10.0 * right)).abs() < 1e-5);
+
+        // A line would leave [0, 1] eventually, so the boundary values are held instead
+
+        let probabilities = bounded(0.0, 1.0, y.iter().map(|y| y / 5.0).collect());
+        assert!(grid(-10.0, 20.0, 30000)
+            .iter()
+            .map(|&x| probabilities.interpolate(x))
+            .all(|v| (0.0..=1.0).contains(&v)));
+        assert_eq!(probabilities.interpolate(-10.0), 1.0);
+        assert_eq!(probabilities.interpolate(20.0), 1.0);
+    }
+
+    #[test]
+    fn test_bounded_matches_cubic_within_bounds() {
+        // Loose bounds leave the natural cubic spline untouched
+        let x = grid(0.0, 3.0, 12);
+        let y: Vec<f64> = x.iter().map(|x| x.sin()).collect();
+        let cubic = Interpolator::new(
+            x.clone(),
+            y.clone(),
+            InterpolationType::Cubic,
+            ExtrapolationStrategy::None,
+        );
+        let bounded = Interpolator::new(
+            x,
+            y,
+            InterpolationType::Bounded {
+                lower: -10.0,
+                upper: 10.0,
+            },
+            ExtrapolationStrategy::None,
+        );
+        for x in grid(0.0, 3.0, 100) {
+            assert!((bounded.interpolate(x) - cubic.interpolate(x)).abs() < 1e-12);
+        }
+
+        let kind: InterpolationType = "Bounded(0, inf)".parse().unwrap();
+        assert_eq!(
+            kind,
+            InterpolationType::Bounded {
+                lower: 0.0,
+                upper: f64::INFINITY
+            }
+        );
+        assert_eq!(kind.to_string(), "Bounded(0, inf)");
+
+        // Functions returning a result report invalid bounds instead of panicking
+        let x = grid(0.0, 3.0, 6);
+        let y: Vec<f64> = x.iter().map(|x| x.sin()).collect();
+        for kind in [
+            InterpolationType::Bounded {
+                lower: 1.0,
+                upper: 0.0,
+            },
+            InterpolationType::Bounded {
+                lower: 0.5,
+                upper: 1.0,
+            },
+        ] {
+            assert!(simplify(&x, &y, kind, 1e-3).is_err());
+            let schemes = [(kind, ExtrapolationStrategy::ExtendSpline)];
+            assert!(cross_validate(&x, &y, &schemes, ValidationMethod::LeaveOneOut).is_err());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "y_values must be within the bounds")]
+    fn test_bounded_rejects_values_out_of_bounds() {
+        Interpolator::new(
+            vec![0.0, 1.0, 2.0],
+            vec![0.5, -0.1, 0.5],
+            InterpolationType::Bounded {
+                lower: 0.0,
+                upper: 1.0,
+            },
+            ExtrapolationStrategy::None,
+        );
+    }
+}
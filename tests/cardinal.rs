@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{
+        CatmullRomParameterization, ExtrapolationStrategy, InterpolationType, Interpolator,
+    };
+    const EPSILON: f64 = 1e-9;
+
+    fn catmull_rom(
+        x_values: Vec<f64>,
+        y_values: Vec<f64>,
+        tension: f64,
+        parameterization: CatmullRomParameterization,
+    ) -> Interpolator {
+        Interpolator::new(
+            x_values,
+            y_values,
+            InterpolationType::Cardinal {
+                tension,
+                parameterization,
+            },
+            ExtrapolationStrategy::None,
+        )
+    }
+
+    #[test]
+    fn test_catmull_rom_uniform() {
+        let x_values = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+        let y_values = vec![0.0, 1.0, 4.0, 9.0, 16.0];
+        let interpolator = catmull_rom(
+            x_values.clone(),
+            y_values.clone(),
+            0.0,
+            CatmullRomParameterization::Uniform,
+        );
+        for (&x, &y) in x_values.iter().zip(&y_values) {
+            assert!((interpolator.interpolate(x) - y).abs() < EPSILON);
+        }
+        // Central differences are exact for a parabola, so interior segments reproduce it
+        assert!((interpolator.interpolate(1.5) - 2.25).abs() < EPSILON);
+        assert!((interpolator.interpolate(2.5) - 6.25).abs() < EPSILON);
+
+        // Slopes are continuous across the knots
+        let h = 1e-7;
+        for &x in &[1.0, 2.0, 3.0] {
+            let left = (interpolator.interpolate(x) - interpolator.interpolate(x - h)) / h;
+            let right = (interpolator.interpolate(x + h) - interpolator.interpolate(x)) / h;
+            assert!((left - right).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_cardinal_tension() {
+        let x_values = vec![0.0, 1.0, 2.0, 3.0];
+        let y_values = vec![0.0, 1.0, 0.0, 1.0];
+        // Full tension gives zero slopes at the knots
+        let interpolator = catmull_rom(
+            x_values.clone(),
+            y_values.clone(),
+            1.0,
+            CatmullRomParameterization::Uniform,
+        );
+        assert!((interpolator.interpolate(0.5) - 0.5).abs() < EPSILON);
+        let h = 1e-7;
+        assert!((interpolator.interpolate(1.0 + h) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Tension must be finite")]
+    fn test_cardinal_rejects_non_finite_tension() {
+        catmull_rom(
+            vec![0.0, 1.0, 2.0],
+            vec![0.0, 1.0, 0.0],
+            f64::NAN,
+            CatmullRomParameterization::Uniform,
+        );
+    }
+
+    #[test]
+    fn test_catmull_rom_locality() {
+        let x_values: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        let y_values: Vec<f64> = x_values.iter().map(|x| (0.7 * x).sin()).collect();
+        let mut edited = y_values.clone();
+        edited[5] += 1.0;
+        for parameterization in [
+            CatmullRomParameterization::Uniform,
+            CatmullRomParameterization::Centripetal,
+            CatmullRomParameterization::Chordal,
+        ] {
+            let original = catmull_rom(x_values.clone(), y_values.clone(), 0.2, parameterization);
+            let modified = catmull_rom(x_values.clone(), edited.clone(), 0.2, parameterization);
+            // Only the segments within two knots of the edited point move
+            for &x in &[0.5, 1.5, 2.5, 7.5, 8.5] {
+                assert_eq!(original.interpolate(x), modified.interpolate(x));
+            }
+            for &x in &[3.5, 4.5, 5.5, 6.5] {
+                assert_ne!(original.interpolate(x), modified.interpolate(x));
+            }
+        }
+    }
+
+    #[test]
+    fn test_centripetal_reduces_overshoot() {
+        // A sharp step makes the uniform spline overshoot more than the centripetal one
+        let x_values = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+        let y_values = vec![0.0, 0.0, 10.0, 10.0, 10.0];
+        let uniform = catmull_rom(
+            x_values.clone(),
+            y_values.clone(),
+            0.0,
+            CatmullRomParameterization::Uniform,
+        );
+        let centripetal = catmull_rom(
+            x_values,
+            y_values,
+            0.0,
+            CatmullRomParameterization::Centripetal,
+        );
+        let overshoot = |interpolator: &Interpolator| {
+            (0..=400)
+                .map(|i| interpolator.interpolate(i as f64 / 100.0))
+                .fold(0.0, |m: f64, y| m.max(y - 10.0).max(-y))
+        };
+        assert!(overshoot(&centripetal) < overshoot(&uniform));
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::approximation::Chebyshev;
+    use std::f64::consts::PI;
+    const EPSILON: f64 = 1e-10;
+
+    #[test]
+    fn test_chebyshev_fixed_degree() {
+        let cubic = |x: f64| x.powi(3) - 2.0 * x + 1.0;
+        let approximation = Chebyshev::new(&cubic, -2.0, 3.0, 3);
+        assert_eq!(approximation.degree(), 3);
+        for &x in &[-2.0, -0.7, 0.0, 1.3, 3.0] {
+            assert!((approximation.evaluate(x) - cubic(x)).abs() < EPSILON);
+        }
+        let derivative = approximation.derivative();
+        assert!((derivative.evaluate(1.5) - (3.0 * 1.5 * 1.5 - 2.0)).abs() < EPSILON);
+        // The integral of x^3 - 2x + 1 over [-2, 3] is 65/4 - 5 + 5
+        assert!((approximation.definite_integral() - 16.25).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_chebyshev_adaptive() {
+        let function = |x: f64| (x.sin() + 0.5 * (3.0 * x).cos()).exp();
+        let approximation = Chebyshev::adaptive(&function, 0.0, 2.0 * PI, 1e-14, 500).unwrap();
+        assert!(approximation.degree() > 10 && approximation.degree() < 200);
+        for i in 0..=100 {
+            let x = 2.0 * PI * i as f64 / 100.0;
+            assert!((approximation.evaluate(x) - function(x)).abs() < 1e-12);
+        }
+
+        let integral = approximation.integral();
+        assert!(integral.evaluate(0.0).abs() < EPSILON);
+        assert!((integral.evaluate(2.0 * PI) - approximation.definite_integral()).abs() < EPSILON);
+
+        // A function with a kink doesn't converge at a low degree
+        assert!(Chebyshev::adaptive(&|x: f64| x.abs(), -1.0, 1.0, 1e-14, 64).is_err());
+
+        // The degree never exceeds the maximum, even for the first sampling
+        let cubic = Chebyshev::adaptive(&|x: f64| x.powi(3) - x, -1.0, 1.0, 1e-14, 5).unwrap();
+        assert_eq!(cubic.degree(), 3);
+        assert!(Chebyshev::adaptive(&|x: f64| x.exp(), -1.0, 1.0, 1e-14, 4).is_err());
+    }
+
+    #[test]
+    fn test_chebyshev_calculus() {
+        let approximation = Chebyshev::adaptive(&|x: f64| x.sin(), 0.0, PI, 1e-15, 100).unwrap();
+        let derivative = approximation.derivative();
+        let integral = approximation.integral();
+        for &x in &[0.1, 1.0, 2.5] {
+            assert!((derivative.evaluate(x) - x.cos()).abs() < 1e-12);
+            assert!((integral.evaluate(x) - (1.0 - x.cos())).abs() < 1e-12);
+        }
+        assert!((approximation.definite_integral() - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_chebyshev_roots() {
+        let approximation =
+            Chebyshev::adaptive(&|x: f64| (3.0 * x).sin(), -0.5, 10.0, 1e-14, 500).unwrap();
+        let roots = approximation.roots().unwrap();
+        let expected: Vec<f64> = (0..10).map(|k| k as f64 * PI / 3.0).collect();
+        assert_eq!(roots.len(), expected.len(), "Roots found: {:?}", roots);
+        for (root, exp) in roots.iter().zip(expected) {
+            assert!((root - exp).abs() < 1e-10, "Expected {}, got {}", exp, root);
+        }
+
+        let line = Chebyshev::from_coefficients(vec![0.5, 1.0], 0.0, 4.0);
+        assert_eq!(line.roots().unwrap(), vec![1.0]);
+        let constant = Chebyshev::from_coefficients(vec![1.0], 0.0, 4.0);
+        assert!(constant.roots().unwrap().is_empty());
+    }
+}
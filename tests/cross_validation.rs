@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{
+        cross_validate, standard_schemes, ExtrapolationStrategy, InterpolationType,
+        ValidationMethod,
+    };
+
+    #[test]
+    fn test_cross_validation_picks_matching_scheme() {
+        // A straight line is predicted exactly by linear interpolation, with spline extrapolation at the ends
+        let x: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        let y: Vec<f64> = x.iter().map(|x| 3.0 * x - 2.0).collect();
+        let report =
+            cross_validate(&x, &y, &standard_schemes(), ValidationMethod::LeaveOneOut).unwrap();
+        assert_eq!(report.scores.len(), standard_schemes().len());
+        let winner = report.winner();
+        assert!(winner.rmse < 1e-12 && winner.max_error < 1e-12);
+        assert_eq!(winner.extrap_strategy, ExtrapolationStrategy::ExtendSpline);
+        assert_eq!(winner.evaluated, 10);
+        assert!(report.scores.windows(2).all(|w| w[0].rmse <= w[1].rmse));
+
+        // A smooth curve favours the cubic spline over the steps
+        let y: Vec<f64> = x.iter().map(|x| (x / 3.0).sin()).collect();
+        let report = cross_validate(
+            &x,
+            &y,
+            &[
+                (
+                    InterpolationType::ConstantForward,
+                    ExtrapolationStrategy::Constant,
+                ),
+                (InterpolationType::Linear, ExtrapolationStrategy::Constant),
+                (InterpolationType::Cubic, ExtrapolationStrategy::Constant),
+            ],
+            ValidationMethod::KFold(3),
+        )
+        .unwrap();
+        assert_eq!(report.winner().interpolation_type, InterpolationType::Cubic);
+        assert_eq!(
+            report.scores[2].interpolation_type,
+            InterpolationType::ConstantForward
+        );
+    }
+
+    #[test]
+    fn test_cross_validation_metrics() {
+        // Leaving out the middle point of a tent gives an error of 1, the ends are extrapolated exactly
+        let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let y = [0.0, 1.0, 2.0, 1.0, 0.0];
+        let report = cross_validate(
+            &x,
+            &y,
+            &[(
+                InterpolationType::Linear,
+                ExtrapolationStrategy::ExtendSpline,
+            )],
+            ValidationMethod::LeaveOneOut,
+        )
+        .unwrap();
+        let score = report.winner();
+        // Errors are 0, 0, 1, 0, 0
+        assert!((score.rmse - (1.0f64 / 5.0).sqrt()).abs() < 1e-12);
+        assert_eq!(score.max_error, 1.0);
+
+        // Without extrapolation the end points can't be predicted, the scheme ranks last
+        let report = cross_validate(
+            &x,
+            &y,
+            &[
+                (InterpolationType::Linear, ExtrapolationStrategy::None),
+                (
+                    InterpolationType::Quadratic,
+                    ExtrapolationStrategy::Constant,
+                ),
+            ],
+            ValidationMethod::LeaveOneOut,
+        )
+        .unwrap();
+        assert_eq!(
+            report.scores[1].extrap_strategy,
+            ExtrapolationStrategy::None
+        );
+        assert_eq!(report.scores[1].skipped, 2);
+        assert_eq!(report.scores[1].evaluated, 3);
+    }
+
+    #[test]
+    fn test_cross_validation_invalid_input() {
+        let schemes = standard_schemes();
+        let x = [0.0, 1.0, 2.0, 3.0];
+        let y = [0.0, 1.0, 0.0, 1.0];
+        assert!(cross_validate(&x, &y[..3], &schemes, ValidationMethod::LeaveOneOut).is_err());
+        assert!(cross_validate(
+            &[1.0, 0.0, 2.0],
+            &y[..3],
+            &schemes,
+            ValidationMethod::LeaveOneOut
+        )
+        .is_err());
+        assert!(cross_validate(&x, &y, &[], ValidationMethod::LeaveOneOut).is_err());
+        assert!(cross_validate(&x, &y, &schemes, ValidationMethod::KFold(1)).is_err());
+        assert!(cross_validate(&x, &y, &schemes, ValidationMethod::KFold(5)).is_err());
+        assert!(cross_validate(&x, &y, &schemes, ValidationMethod::KFold(4)).is_ok());
+    }
+}
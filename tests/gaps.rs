@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{
+        svg_path, ExtrapolationStrategy, GapPolicy, InterpolationType, Interpolator,
+        InterpolatorBuilder,
+    };
+    const EPSILON: f64 = 1e-12;
+
+    fn data() -> (Vec<f64>, Vec<f64>) {
+        (
+            vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0],
+            vec![0.0, 1.0, f64::NAN, 3.0, f64::NAN, f64::NAN, 6.0, 7.0],
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "use InterpolatorBuilder to handle gaps")]
+    fn test_nan_is_rejected() {
+        Interpolator::new(
+            vec![0.0, 1.0, 2.0],
+            vec![0.0, f64::NAN, 2.0],
+            InterpolationType::Cubic,
+            ExtrapolationStrategy::None,
+        );
+    }
+
+    #[test]
+    fn test_drop_and_fill_gaps() {
+        let (x_values, y_values) = data();
+        let build = |gap_policy: GapPolicy| {
+            InterpolatorBuilder::new(InterpolationType::Linear)
+                .data(x_values.clone(), y_values.clone())
+                .gap_policy(gap_policy)
+                .build()
+                .unwrap()
+        };
+        for interpolator in [build(GapPolicy::Drop), build(GapPolicy::Fill)] {
+            for i in 0..=70 {
+                let x = i as f64 / 10.0;
+                assert!((interpolator.interpolate(x) - x).abs() < EPSILON);
+            }
+            assert!(interpolator.gaps().is_empty());
+        }
+
+        // Filled nodes take the value of the spline through the valid points and stay as nodes
+        let y_values = vec![0.0, 1.0, f64::NAN, 9.0, 16.0, f64::NAN];
+        let x_values = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let dropped = InterpolatorBuilder::new(InterpolationType::Cubic)
+            .data(x_values.clone(), y_values.clone())
+            .gap_policy(GapPolicy::Drop)
+            .build()
+            .unwrap();
+        let filled = InterpolatorBuilder::new(InterpolationType::Cubic)
+            .data(x_values, y_values)
+            .gap_policy(GapPolicy::Fill)
+            .extrapolation(ExtrapolationStrategy::ExtendSpline)
+            .build()
+            .unwrap();
+        assert!((filled.interpolate(2.0) - dropped.interpolate(2.0)).abs() < EPSILON);
+        assert_eq!(filled.node_sensitivities(2.5).len(), 6);
+        assert_eq!(dropped.node_sensitivities(2.5).len(), 4);
+    }
+
+    #[test]
+    fn test_keep_gaps() {
+        let (x_values, mut y_values) = data();
+        y_values[0] = f64::NAN;
+        let interpolator = InterpolatorBuilder::new(InterpolationType::Linear)
+            .data(x_values, y_values)
+            .gap_policy(GapPolicy::Keep)
+            .extrapolation(ExtrapolationStrategy::Constant)
+            .build()
+            .unwrap();
+        assert_eq!(
+            interpolator.gaps(),
+            &[(f64::NEG_INFINITY, 1.0), (1.0, 3.0), (3.0, 6.0)]
+        );
+        assert_eq!(interpolator.interpolate(1.0), 1.0);
+        assert_eq!(interpolator.interpolate(3.0), 3.0);
+        assert_eq!(interpolator.interpolate(6.5), 6.5);
+        assert_eq!(interpolator.interpolate(8.0), 7.0);
+        for &x in &[-1.0, 0.5, 2.0, 4.5] {
+            assert!(interpolator.interpolate(x).is_nan());
+            assert!(interpolator.try_interpolate(x).is_err());
+        }
+        assert_eq!(interpolator.try_interpolate(6.5), Ok(6.5));
+    }
+
+    #[test]
+    fn test_max_gap() {
+        let (x_values, y_values) = data();
+        // The single missing point is bridged, the two missing points form a gap of length 3 which is kept
+        let interpolator = InterpolatorBuilder::new(InterpolationType::Linear)
+            .data(x_values.clone(), y_values.clone())
+            .gap_policy(GapPolicy::Fill)
+            .max_gap(2.5)
+            .build()
+            .unwrap();
+        assert_eq!(interpolator.gaps(), &[(3.0, 6.0)]);
+        assert!((interpolator.interpolate(1.5) - 1.5).abs() < EPSILON);
+        assert!(interpolator.interpolate(4.0).is_nan());
+        assert!(interpolator.try_interpolate(8.0).is_err());
+
+        assert!(InterpolatorBuilder::new(InterpolationType::Linear)
+            .data(x_values.clone(), y_values.clone())
+            .build()
+            .is_err());
+        assert!(InterpolatorBuilder::new(InterpolationType::Linear)
+            .data(x_values, vec![f64::NAN; 8])
+            .gap_policy(GapPolicy::Drop)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_gaps_cut_the_spline() {
+        let x_values: Vec<f64> = (0..12).map(|i| i as f64).collect();
+        let mut y_values: Vec<f64> = x_values.iter().map(|x| (x / 2.0).sin()).collect();
+        y_values[5] = f64::NAN;
+        for interpolation_type in [
+            InterpolationType::Cubic,
+            InterpolationType::Tension(2.0),
+            InterpolationType::ConvexityPreserving,
+            InterpolationType::ConstantBackward,
+        ] {
+            let interpolator = InterpolatorBuilder::new(interpolation_type)
+                .data(x_values.clone(), y_values.clone())
+                .gap_policy(GapPolicy::Keep)
+                .extrapolation(ExtrapolationStrategy::ExtendSpline)
+                .build()
+                .unwrap();
+            // Each side of the gap matches the interpolant through its own points only
+            for (range, sample) in [(0..5, [-0.5, 1.5, 3.7]), (6..12, [6.0, 8.2, 12.5])] {
+                let piece = Interpolator::new(
+                    x_values[range.clone()].to_vec(),
+                    y_values[range].to_vec(),
+                    interpolation_type,
+                    ExtrapolationStrategy::ExtendSpline,
+                );
+                for x in sample {
+                    assert!(
+                        (interpolator.interpolate(x) - piece.interpolate(x)).abs() < EPSILON,
+                        "{:?} interpolation at x = {}",
+                        interpolation_type,
+                        x
+                    );
+                }
+            }
+            assert!(interpolator.interpolate(5.0).is_nan());
+        }
+
+        let interpolator = InterpolatorBuilder::new(InterpolationType::Cubic)
+            .data(x_values.clone(), y_values.clone())
+            .gap_policy(GapPolicy::Keep)
+            .build()
+            .unwrap();
+        let weights = interpolator.node_sensitivities(2.5);
+        assert!(weights[5..].iter().all(|&w| w == 0.0));
+        assert!((weights.iter().sum::<f64>() - 1.0).abs() < EPSILON);
+        assert!(interpolator.node_sensitivities(5.0)[0].is_nan());
+        assert!(interpolator.inverse(0.5).is_err());
+
+        // The Bézier curves leave out the segment across the gap, so the path is broken there
+        let curves = interpolator.to_bezier().unwrap();
+        assert_eq!(curves.len(), 9);
+        assert!(curves.iter().all(|curve| curve
+            .control_points()
+            .iter()
+            .all(|&(x, _)| x <= 4.0 || x >= 6.0)));
+        assert!(interpolator.bezier_segment(4).is_err());
+        assert_eq!(svg_path(&curves).unwrap().matches('M').count(), 2);
+
+        // Invalid parameters are reported instead of panicking
+        for interpolation_type in [
+            InterpolationType::Tension(-1.0),
+            InterpolationType::Bounded {
+                lower: 1.0,
+                upper: 0.0,
+            },
+        ] {
+            assert!(InterpolatorBuilder::new(interpolation_type)
+                .data(x_values.clone(), y_values.clone())
+                .gap_policy(GapPolicy::Drop)
+                .build()
+                .is_err());
+        }
+    }
+}
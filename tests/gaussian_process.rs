@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::regression::{GaussianProcess, Kernel};
+
+    #[test]
+    fn test_kernels() {
+        let se = Kernel::SquaredExponential {
+            variance: 2.0,
+            length_scale: 0.5,
+        };
+        assert_eq!(se.evaluate(1.0, 1.0), 2.0);
+        assert!((se.evaluate(0.0, 0.5) - 2.0 * (-0.5f64).exp()).abs() < 1e-15);
+
+        let s3 = 3f64.sqrt();
+        let matern32 = Kernel::Matern32 {
+            variance: 1.0,
+            length_scale: 1.0,
+        };
+        assert!((matern32.evaluate(0.0, 1.0) - (1.0 + s3) * (-s3).exp()).abs() < 1e-15);
+        let s5 = 5f64.sqrt();
+        let matern52 = Kernel::Matern52 {
+            variance: 1.0,
+            length_scale: 1.0,
+        };
+        assert!((matern52.evaluate(1.0, 0.0) - (1.0 + s5 + 5.0 / 3.0) * (-s5).exp()).abs() < 1e-15);
+
+        let periodic = Kernel::Periodic {
+            variance: 1.5,
+            length_scale: 1.0,
+            period: 2.0,
+        };
+        assert!((periodic.evaluate(0.3, 4.3) - 1.5).abs() < 1e-12);
+        assert_eq!(periodic.variance(), 1.5);
+    }
+
+    #[test]
+    fn test_gaussian_process_interpolates_with_uncertainty() {
+        let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let y = [1.0, 2.0, 0.5, -1.0, 0.0];
+        let kernel = Kernel::Matern52 {
+            variance: 1.0,
+            length_scale: 1.0,
+        };
+        let gp = GaussianProcess::new(&x, &y, kernel, 1e-12).unwrap();
+        for (xi, yi) in x.iter().zip(&y) {
+            let (mean, variance) = gp.predict(*xi);
+            assert!((mean - yi).abs() < 1e-6);
+            assert!(variance < 1e-6);
+        }
+        // Uncertain between the pillars and back to the prior far away
+        let (_, between) = gp.predict(2.5);
+        assert!(between > 1e-3);
+        let (mean, far) = gp.predict(100.0);
+        assert!((mean - 0.5).abs() < 1e-10);
+        assert!((far - 1.0).abs() < 1e-10);
+
+        // Noise smooths the data, the posterior variance at the points stays below the noise
+        let noisy = GaussianProcess::new(&x, &y, kernel, 0.1).unwrap();
+        let (mean, variance) = noisy.predict(1.0);
+        assert!(mean < 2.0 - 1e-3);
+        assert!(variance > 0.0 && variance < 0.1);
+    }
+
+    #[test]
+    fn test_gaussian_process_fit() {
+        // Smooth function with deterministic pseudo-noise of standard deviation 0.05
+        let x: Vec<f64> = (0..40).map(|i| i as f64 * 0.25).collect();
+        let noise = |i: usize| {
+            let hash = ((i as f64 * 12.9898).sin() * 43758.5453).fract().abs();
+            0.05 * 12f64.sqrt() * (hash - 0.5)
+        };
+        let y: Vec<f64> = x
+            .iter()
+            .enumerate()
+            .map(|(i, x)| x.sin() + noise(i))
+            .collect();
+        let initial = Kernel::SquaredExponential {
+            variance: 0.1,
+            length_scale: 0.2,
+        };
+        let start = GaussianProcess::new(&x, &y, initial, 0.5).unwrap();
+        let fitted = GaussianProcess::fit(&x, &y, initial, 0.5).unwrap();
+        assert!(fitted.log_marginal_likelihood() > start.log_marginal_likelihood() + 10.0);
+        assert!(fitted.noise_variance() > 1e-4 && fitted.noise_variance() < 1e-2);
+        match fitted.kernel() {
+            Kernel::SquaredExponential { length_scale, .. } => {
+                assert!(length_scale > 0.5 && length_scale < 3.0)
+            }
+            _ => panic!("The kind of kernel must not change"),
+        }
+        for i in 0..=30 {
+            let t = 1.0 + i as f64 * 0.25;
+            let (mean, variance) = fitted.predict(t);
+            assert!((mean - t.sin()).abs() < 0.06);
+            assert!(variance < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_gaussian_process_periodic_and_errors() {
+        let x: Vec<f64> = (0..12).map(|i| i as f64 * 0.25).collect();
+        let y: Vec<f64> = x.iter().map(|x| (std::f64::consts::PI * x).sin()).collect();
+        let kernel = Kernel::Periodic {
+            variance: 1.0,
+            length_scale: 1.0,
+            period: 2.0,
+        };
+        let gp = GaussianProcess::fit(&x, &y, kernel, 1e-6).unwrap();
+        // The periodic kernel extrapolates the pattern
+        let (inside, _) = gp.predict(0.6);
+        let (outside, _) = gp.predict(10.6);
+        assert!((inside - outside).abs() < 1e-6);
+        assert!((outside - (0.6 * std::f64::consts::PI).sin()).abs() < 1e-2);
+
+        assert!(GaussianProcess::new(&[0.0], &[], kernel, 0.0).is_err());
+        assert!(GaussianProcess::new(&[0.0], &[1.0], kernel, -1.0).is_err());
+        assert!(GaussianProcess::new(
+            &[0.0],
+            &[1.0],
+            Kernel::Matern32 {
+                variance: 0.0,
+                length_scale: 1.0
+            },
+            0.0
+        )
+        .is_err());
+        // Repeated points without noise make the covariance singular
+        assert!(GaussianProcess::new(&[0.0, 0.0], &[1.0, 2.0], kernel, 0.0).is_err());
+    }
+}
@@ -344,4 +344,16 @@ mod tests {
         assert_eq!(interp.interpolate(1.5), 20.0);
         assert_eq!(interp.interpolate(2.5), 30.0);
     }
+
+    #[test]
+    fn test_constant_interpolation_extend_spline() {
+        let interp = Interpolator::new(
+            vec![0.0, 1.0, 2.0, 3.0],
+            vec![10.0, 20.0, 30.0, 40.0],
+            InterpolationType::ConstantBackward,
+            ExtrapolationStrategy::ExtendSpline,
+        );
+        assert_eq!(interp.interpolate(-1.0), 10.0); // Boundary steps are extended
+        assert_eq!(interp.interpolate(4.0), 40.0);
+    }
 }
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{ExtrapolationStrategy, InterpolationType, Interpolator, SincWindow};
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn test_inverse_linear() {
+        let interpolator = Interpolator::new(
+            vec![0.0, 1.0, 2.0, 3.0],
+            vec![0.0, 2.0, 3.0, 6.0],
+            InterpolationType::Linear,
+            ExtrapolationStrategy::None,
+        );
+        assert_eq!(interpolator.inverse(0.0).unwrap(), 0.0);
+        assert_eq!(interpolator.inverse(2.0).unwrap(), 1.0);
+        assert!((interpolator.inverse(2.5).unwrap() - 1.5).abs() < EPSILON);
+        assert!((interpolator.inverse(4.5).unwrap() - 2.5).abs() < EPSILON);
+        assert!(interpolator.inverse(7.0).is_err());
+    }
+
+    #[test]
+    fn test_inverse_cubic_decreasing() {
+        // Discount factors as a function of the tenor
+        let x_values: Vec<f64> = vec![0.0, 1.0, 2.0, 5.0, 10.0];
+        let y_values: Vec<f64> = x_values.iter().map(|&t| (-0.03 * t).exp()).collect();
+        let interpolator = Interpolator::new(
+            x_values,
+            y_values,
+            InterpolationType::Cubic,
+            ExtrapolationStrategy::None,
+        );
+        for &x in &[0.3, 1.7, 4.2, 9.9] {
+            let y = interpolator.interpolate(x);
+            let inverse = interpolator.inverse(y).unwrap();
+            assert!(
+                (inverse - x).abs() < EPSILON,
+                "Expected {}, got {}",
+                x,
+                inverse
+            );
+        }
+    }
+
+    #[test]
+    fn test_inverse_not_monotone() {
+        let interpolator = Interpolator::new(
+            vec![0.0, 1.0, 2.0],
+            vec![0.0, 2.0, 1.0],
+            InterpolationType::Linear,
+            ExtrapolationStrategy::None,
+        );
+        assert!(interpolator.inverse(0.5).is_err());
+
+        // Monotone data can still produce a cubic spline that overshoots
+        let interpolator = Interpolator::new(
+            vec![0.0, 1.0, 2.0, 3.0],
+            vec![0.0, 0.0, 1.0, 1.0],
+            InterpolationType::Cubic,
+            ExtrapolationStrategy::None,
+        );
+        assert!(interpolator.inverse(0.5).is_err());
+    }
+
+    #[test]
+    fn test_inverse_interpolator() {
+        let x_values: Vec<f64> = vec![0.0, 1.0, 2.0, 3.0];
+        let y_values: Vec<f64> = x_values.iter().map(|&x| x.powi(2) + x).collect();
+        let interpolator = Interpolator::new(
+            x_values,
+            y_values,
+            InterpolationType::Cubic,
+            ExtrapolationStrategy::None,
+        );
+        let inverse = interpolator
+            .inverse_interpolator(
+                20,
+                InterpolationType::Linear,
+                ExtrapolationStrategy::Constant,
+            )
+            .unwrap();
+        for &x in &[0.5, 1.25, 2.75] {
+            let y = interpolator.interpolate(x);
+            assert!((inverse.interpolate(y) - x).abs() < 1e-3);
+        }
+        assert_eq!(inverse.interpolate(100.0), 3.0);
+
+        // Types that can't be built on the sampled values are reported instead of panicking
+        for interpolation_type in [
+            InterpolationType::Bounded {
+                lower: 0.0,
+                upper: 1.0,
+            },
+            InterpolationType::Tension(-1.0),
+            InterpolationType::Sinc(SincWindow::Lanczos(3)),
+        ] {
+            assert!(interpolator
+                .inverse_interpolator(20, interpolation_type, ExtrapolationStrategy::None)
+                .is_err());
+        }
+    }
+}
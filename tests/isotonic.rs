@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{ExtrapolationStrategy, InterpolationType, Interpolator};
+    use numerics_rs::regression::{isotonic_interpolator, isotonic_regression, Monotonicity};
+
+    #[test]
+    fn test_isotonic_regression_pools_violators() {
+        let y = [1.0, 3.0, 2.0, 4.0, 3.5, 3.0, 6.0];
+        let fitted = isotonic_regression(&y, None, Monotonicity::Increasing).unwrap();
+        assert_eq!(fitted, vec![1.0, 2.5, 2.5, 3.5, 3.5, 3.5, 6.0]);
+
+        // Weights pull the pooled value towards the heavier points
+        let weights = [1.0, 3.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+        let fitted = isotonic_regression(&y, Some(&weights), Monotonicity::Increasing).unwrap();
+        assert_eq!(fitted[1], 2.75);
+        assert_eq!(fitted[2], 2.75);
+
+        // Monotone data is left as it is
+        let monotone = [5.0, 4.0, 4.0, 1.0];
+        let fitted = isotonic_regression(&monotone, None, Monotonicity::Decreasing).unwrap();
+        assert_eq!(fitted, monotone.to_vec());
+        let fitted = isotonic_regression(&monotone, None, Monotonicity::Increasing).unwrap();
+        assert_eq!(fitted, vec![3.5; 4]);
+
+        assert!(isotonic_regression(&y, Some(&[1.0]), Monotonicity::Increasing).is_err());
+        assert!(isotonic_regression(&[1.0, f64::NAN], None, Monotonicity::Increasing).is_err());
+        assert!(isotonic_regression(&[], None, Monotonicity::Increasing)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_isotonic_regression_is_optimal() {
+        // The fit must beat every other monotone sequence, check small perturbations of it
+        let y = [0.3, 0.1, 0.5, 0.45, 0.7, 0.65, 0.6, 1.0];
+        let weights = [1.0, 2.0, 1.0, 0.5, 1.0, 1.0, 3.0, 1.0];
+        let fitted = isotonic_regression(&y, Some(&weights), Monotonicity::Increasing).unwrap();
+        assert!(fitted.windows(2).all(|w| w[0] <= w[1]));
+        let loss = |f: &[f64]| {
+            f.iter()
+                .zip(&y)
+                .zip(&weights)
+                .map(|((f, y), w)| w * (f - y).powi(2))
+                .sum::<f64>()
+        };
+        for i in 0..fitted.len() {
+            for delta in [-1e-3, 1e-3] {
+                let mut perturbed = fitted.clone();
+                perturbed[i] += delta;
+                if perturbed.windows(2).all(|w| w[0] <= w[1]) {
+                    assert!(loss(&perturbed) > loss(&fitted));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_monotone_cubic_interpolation() {
+        let x = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let y = vec![0.0, 0.1, 0.1, 0.9, 1.0, 1.0];
+        let interpolator = Interpolator::new(
+            x.clone(),
+            y.clone(),
+            InterpolationType::MonotoneCubic,
+            ExtrapolationStrategy::None,
+        );
+        for (xi, yi) in x.iter().zip(&y) {
+            assert!((interpolator.interpolate(*xi) - yi).abs() < 1e-12);
+        }
+        // No overshoot on the flat parts, unlike the natural cubic spline
+        let cubic = Interpolator::new(x, y, InterpolationType::Cubic, ExtrapolationStrategy::None);
+        let samples: Vec<f64> = (0..=500)
+            .map(|i| interpolator.interpolate(i as f64 / 100.0))
+            .collect();
+        assert!(samples.windows(2).all(|w| w[1] >= w[0] - 1e-15));
+        assert!((0..=500).any(|i| cubic.interpolate(i as f64 / 100.0) > 1.0));
+
+        // Slopes are continuous at the knots
+        let h = 1e-7;
+        for knot in [1.0, 2.0, 3.0, 4.0] {
+            let left = (interpolator.interpolate(knot) - interpolator.interpolate(knot - h)) / h;
+            let right = (interpolator.interpolate(knot + h) - interpolator.interpolate(knot)) / h;
+            assert!((left - right).abs() < 1e-5);
+        }
+        assert_eq!(
+            "MonotoneCubic".parse::<InterpolationType>().unwrap(),
+            InterpolationType::MonotoneCubic
+        );
+    }
+
+    #[test]
+    fn test_isotonic_interpolator() {
+        // Noisy CDF quotes with a small violation
+        let x = [90.0, 95.0, 100.0, 105.0, 110.0];
+        let y = [0.10, 0.32, 0.30, 0.70, 0.95];
+        let interpolator = isotonic_interpolator(
+            &x,
+            &y,
+            None,
+            Monotonicity::Increasing,
+            ExtrapolationStrategy::Constant,
+        )
+        .unwrap();
+        assert!((interpolator.interpolate(95.0) - 0.31).abs() < 1e-12);
+        assert!((interpolator.interpolate(100.0) - 0.31).abs() < 1e-12);
+        let samples: Vec<f64> = (0..=400)
+            .map(|i| interpolator.interpolate(85.0 + i as f64 * 0.075))
+            .collect();
+        assert!(samples.windows(2).all(|w| w[1] >= w[0]));
+        assert!((interpolator.inverse(0.5).unwrap() - 102.5).abs() < 2.5);
+
+        assert!(isotonic_interpolator(
+            &[1.0, 0.0],
+            &[0.0, 1.0],
+            None,
+            Monotonicity::Increasing,
+            ExtrapolationStrategy::None
+        )
+        .is_err());
+    }
+}
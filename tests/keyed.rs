@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{
+        Abscissa, ExtrapolationStrategy, Identity, InterpolationType, Interpolator,
+        KeyedInterpolator, YearFraction,
+    };
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    const DAY: i64 = 86_400;
+
+    #[test]
+    fn test_keyed_timestamps() {
+        // Pillars at 0, 73 and 365 days, in years of 365 days
+        let origin = 1_700_000_000;
+        let keys = [origin, origin + 73 * DAY, origin + 365 * DAY];
+        let curve = KeyedInterpolator::new(
+            &keys,
+            vec![1.0, 2.0, 6.0],
+            InterpolationType::Linear,
+            ExtrapolationStrategy::None,
+            YearFraction::new(origin, 365.0),
+        );
+        assert_eq!(curve.abscissa(&keys[1]), 0.2);
+        assert!((curve.interpolate(&(origin + 146 * DAY)) - 3.0).abs() < 1e-12);
+        assert!(curve.try_interpolate(&(origin - DAY)).is_err());
+        assert_eq!(curve.interpolator().interpolate(0.2), 2.0);
+
+        // Keys far from the origin don't overflow
+        let far = YearFraction::new(i64::MIN, 365.0);
+        let years = u64::MAX as f64 / (365.0 * DAY as f64);
+        assert!((far.to_f64(&i64::MAX) - years).abs() < 1e-9 * years);
+    }
+
+    #[test]
+    fn test_keyed_system_time() {
+        let start = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+        let keys: Vec<SystemTime> = (0..4)
+            .map(|i| start + Duration::from_secs(i * 91 * DAY as u64))
+            .collect();
+        let curve = KeyedInterpolator::new(
+            &keys,
+            vec![0.0, 1.0, 4.0, 9.0],
+            InterpolationType::Cubic,
+            ExtrapolationStrategy::ExtendSpline,
+            YearFraction::new(start, 364.0),
+        );
+        assert_eq!(curve.abscissa(&keys[2]), 0.5);
+        assert!((curve.interpolate(&keys[3]) - 9.0).abs() < 1e-12);
+        // Times before the origin have negative abscissae
+        let before = start - Duration::from_secs(91 * DAY as u64);
+        assert_eq!(curve.abscissa(&before), -0.25);
+        assert!(curve.interpolate(&before).is_finite());
+
+        let epoch: YearFraction<SystemTime> = YearFraction::default();
+        assert_eq!(epoch.to_f64(&UNIX_EPOCH), 0.0);
+    }
+
+    #[test]
+    fn test_keyed_custom_dates() {
+        #[derive(Clone, Copy)]
+        struct Date {
+            year: i32,
+            month: u32,
+        }
+        // 30/360 month-based day count from January 2024
+        let thirty_360 =
+            |date: &Date| ((date.year - 2024) * 360 + (date.month as i32 - 1) * 30) as f64 / 360.0;
+        let keys = [
+            Date {
+                year: 2024,
+                month: 1,
+            },
+            Date {
+                year: 2024,
+                month: 7,
+            },
+            Date {
+                year: 2026,
+                month: 1,
+            },
+        ];
+        let curve = KeyedInterpolator::new(
+            &keys,
+            vec![0.03, 0.035, 0.04],
+            InterpolationType::Linear,
+            ExtrapolationStrategy::Constant,
+            thirty_360,
+        );
+        let october = Date {
+            year: 2024,
+            month: 10,
+        };
+        assert!((curve.interpolate(&october) - (0.035 + 0.005 / 6.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_keyed_identity_matches_interpolator() {
+        let x = vec![0.0, 1.0, 2.5, 4.0];
+        let y = vec![1.0, 3.0, 2.0, 5.0];
+        let plain = Interpolator::new(
+            x.clone(),
+            y.clone(),
+            InterpolationType::Cubic,
+            ExtrapolationStrategy::None,
+        );
+        let keyed = KeyedInterpolator::new(
+            &x,
+            y,
+            InterpolationType::Cubic,
+            ExtrapolationStrategy::None,
+            Identity,
+        );
+        for t in [0.0, 0.7, 2.5, 3.9] {
+            assert_eq!(keyed.interpolate(&t), plain.interpolate(t));
+        }
+    }
+}
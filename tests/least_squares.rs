@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{fit_least_squares_spline, ExtrapolationStrategy};
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn test_least_squares_spline_reproduces_linear_data() {
+        let x_values: Vec<f64> = (0..20).map(|i| i as f64 * 0.5).collect();
+        let y_values: Vec<f64> = x_values.iter().map(|&x| 2.0 * x - 1.0).collect();
+        let fit = fit_least_squares_spline(
+            &x_values,
+            &y_values,
+            None,
+            &[3.0, 6.0],
+            ExtrapolationStrategy::None,
+        )
+        .unwrap();
+
+        assert_eq!(fit.knots, vec![0.0, 3.0, 6.0, 9.5]);
+        assert_eq!(fit.degrees_of_freedom, 16);
+        assert!(fit.residual_sum_of_squares < EPSILON);
+        assert!((fit.interpolate(4.25) - 7.5).abs() < EPSILON);
+        assert!((fit.knot_values[1] - 5.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_least_squares_spline_noisy_data() {
+        // Deterministic noise of alternating sign on top of a sine wave
+        let x_values: Vec<f64> = (0..101).map(|i| i as f64 * 0.0628).collect();
+        let y_values: Vec<f64> = x_values
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| x.sin() + if i % 2 == 0 { 0.05 } else { -0.05 })
+            .collect();
+        let weights = vec![2.0; x_values.len()];
+        let fit = fit_least_squares_spline(
+            &x_values,
+            &y_values,
+            Some(&weights),
+            &[1.0, 2.0, 3.0, 4.0, 5.0],
+            ExtrapolationStrategy::Constant,
+        )
+        .unwrap();
+
+        for &x in &[0.5, 1.5, 3.3, 4.7] {
+            assert!((fit.interpolate(x) - x.sin()).abs() < 0.02);
+        }
+        assert_eq!(fit.residuals.len(), 101);
+        assert!((fit.residual_variance - 2.0 * 0.05 * 0.05).abs() < 1e-3);
+
+        // Covariance of the knot values is symmetric with positive variances
+        let k = fit.knots.len();
+        for i in 0..k {
+            assert!(fit.covariance[i][i] > 0.0);
+            for j in 0..k {
+                assert!((fit.covariance[i][j] - fit.covariance[j][i]).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_least_squares_spline_invalid_input() {
+        let x_values = vec![0.0, 1.0, 2.0, 3.0];
+        let y_values = vec![0.0, 1.0, 2.0, 3.0];
+        let res = fit_least_squares_spline(
+            &x_values,
+            &y_values,
+            None,
+            &[2.0, 1.0],
+            ExtrapolationStrategy::None,
+        );
+        assert!(res.is_err());
+        let res = fit_least_squares_spline(
+            &x_values,
+            &y_values,
+            None,
+            &[0.5, 1.0, 1.5, 2.0],
+            ExtrapolationStrategy::None,
+        );
+        assert!(res.is_err());
+
+        // Non-finite values are rejected up front
+        let fit = |x: &[f64], weights: Option<&[f64]>, knots: &[f64]| {
+            fit_least_squares_spline(x, &y_values, weights, knots, ExtrapolationStrategy::None)
+        };
+        assert!(fit(&[0.0, f64::NAN, 2.0, 3.0], None, &[]).is_err());
+        assert!(fit(&x_values, Some(&[1.0, f64::INFINITY, 1.0, 1.0]), &[]).is_err());
+        assert!(fit(&x_values, None, &[f64::NAN]).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{ExtrapolationStrategy, InterpolationType, SincWindow};
+    use numerics_rs::regression::Loess;
+
+    #[test]
+    fn test_loess_reproduces_local_polynomials() {
+        // Local fits of degree d reproduce polynomials of degree d exactly
+        let x: Vec<f64> = (0..20).map(|i| i as f64 * 0.5).collect();
+        let line: Vec<f64> = x.iter().map(|x| 2.0 * x - 1.0).collect();
+        let fitted = Loess::new().span(0.3).fit(&x, &line).unwrap();
+        for (f, y) in fitted.iter().zip(&line) {
+            assert!((f - y).abs() < 1e-10);
+        }
+
+        let parabola: Vec<f64> = x.iter().map(|x| x * x - 3.0 * x).collect();
+        let fitted = Loess::new().degree(2).fit(&x, &parabola).unwrap();
+        for (f, y) in fitted.iter().zip(&parabola) {
+            assert!((f - y).abs() < 1e-9);
+        }
+
+        // Degree zero is a weighted moving average, constant data stays constant
+        let fitted = Loess::new().degree(0).fit(&x, &[3.0; 20]).unwrap();
+        assert!(fitted.iter().all(|f| (f - 3.0).abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_loess_smooths_noise() {
+        let x: Vec<f64> = (0..50).map(|i| i as f64 / 49.0).collect();
+        let truth: Vec<f64> = x.iter().map(|x| (3.0 * x).sin()).collect();
+        // Deterministic alternating noise
+        let noisy: Vec<f64> = truth
+            .iter()
+            .enumerate()
+            .map(|(i, y)| y + if i % 2 == 0 { 0.1 } else { -0.1 })
+            .collect();
+        let fitted = Loess::new().span(0.3).fit(&x, &noisy).unwrap();
+        let error = |values: &[f64]| {
+            values
+                .iter()
+                .zip(&truth)
+                .map(|(v, t)| (v - t).powi(2))
+                .sum::<f64>()
+        };
+        assert!(error(&fitted) < error(&noisy) / 10.0);
+    }
+
+    #[test]
+    fn test_loess_robustness() {
+        let x: Vec<f64> = (0..30).map(|i| i as f64).collect();
+        let mut y: Vec<f64> = x.iter().map(|x| 0.5 * x + 1.0).collect();
+        y[10] += 5.0;
+        y[20] -= 50.0;
+
+        // The plain fit is dragged by the outliers, the robust one recovers the line
+        let plain = Loess::new()
+            .span(0.4)
+            .robustness_iterations(0)
+            .fit(&x, &y)
+            .unwrap();
+        let robust = Loess::new().span(0.4).fit(&x, &y).unwrap();
+        assert!((plain[20] - 11.0).abs() > 1.0);
+        for (i, f) in robust.iter().enumerate() {
+            assert!((f - (0.5 * i as f64 + 1.0)).abs() < 1e-6);
+        }
+
+        // Repeated x values are allowed
+        let fitted = Loess::new()
+            .fit(&[0.0, 1.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 1.0, 2.0, 3.0])
+            .unwrap();
+        assert!((fitted[2] - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_loess_interpolator_and_errors() {
+        let x = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let y = [0.0, 2.0, 4.0, 6.0, 8.0, 10.0];
+        let interpolator = Loess::new()
+            .fit_interpolator(
+                &x,
+                &y,
+                InterpolationType::Cubic,
+                ExtrapolationStrategy::None,
+            )
+            .unwrap();
+        assert!((interpolator.interpolate(2.5) - 5.0).abs() < 1e-10);
+
+        assert!(Loess::new().span(0.0).fit(&x, &y).is_err());
+        assert!(Loess::new().degree(3).fit(&x, &y).is_err());
+        assert!(Loess::new().fit(&[1.0, 0.0], &[0.0, 1.0]).is_err());
+        assert!(Loess::new().fit(&x, &y[1..]).is_err());
+        assert!(Loess::new()
+            .fit_interpolator(
+                &[0.0, 0.0, 1.0],
+                &[1.0, 1.0, 2.0],
+                InterpolationType::Linear,
+                ExtrapolationStrategy::None
+            )
+            .is_err());
+
+        // Types that don't accept the smoothed values are reported instead of panicking
+        for (x, interpolation_type) in [
+            (
+                vec![0.0, 1.0, 3.0, 4.0, 5.0, 7.0],
+                InterpolationType::Sinc(SincWindow::Lanczos(3)),
+            ),
+            (
+                x.to_vec(),
+                InterpolationType::Bounded {
+                    lower: 0.0,
+                    upper: 5.0,
+                },
+            ),
+        ] {
+            assert!(Loess::new()
+                .fit_interpolator(&x, &y, interpolation_type, ExtrapolationStrategy::None)
+                .is_err());
+        }
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{neville, rational_extrapolation};
+
+    #[test]
+    fn test_neville_polynomial() {
+        // Four points determine the cubic exactly, in any order
+        let cubic = |x: f64| 2.0 * x.powi(3) - x + 0.5;
+        let x = [3.0, -1.0, 0.5, 2.0];
+        let y: Vec<f64> = x.iter().map(|&x| cubic(x)).collect();
+        for t in [-2.0, 0.0, 1.3, 4.0] {
+            let (value, error) = neville(&x, &y, t).unwrap();
+            assert!((value - cubic(t)).abs() < 1e-11);
+            assert!(error.is_finite());
+        }
+        assert_eq!(neville(&x, &y, 0.5).unwrap().0, cubic(0.5));
+        assert_eq!(neville(&[1.0], &[4.0], 7.0).unwrap(), (4.0, 0.0));
+    }
+
+    #[test]
+    fn test_neville_richardson_extrapolation() {
+        // Central differences of exp at 0 have an error expansion in h^2, extrapolate it to h = 0
+        let steps = [0.4, 0.2, 0.1, 0.05];
+        let h_squared: Vec<f64> = steps.iter().map(|h| h * h).collect();
+        let differences: Vec<f64> = steps
+            .iter()
+            .map(|h: &f64| (h.exp() - (-h).exp()) / (2.0 * h))
+            .collect();
+        let (value, error) = neville(&h_squared, &differences, 0.0).unwrap();
+        assert!((value - 1.0).abs() < 1e-10);
+        assert!((differences[3] - 1.0).abs() > 1e-4);
+        // The estimate is the size of the last correction
+        assert!(error < 1e-8 && (value - 1.0).abs() < 10.0 * error + 1e-14);
+    }
+
+    #[test]
+    fn test_rational_extrapolation() {
+        // A (1, 1) rational function is reproduced from three points
+        let f = |x: f64| (1.0 + 2.0 * x) / (3.0 + x);
+        let x = [1.0, 2.0, 4.0];
+        let y: Vec<f64> = x.iter().map(|&x| f(x)).collect();
+        let (value, _) = rational_extrapolation(&x, &y, 0.0).unwrap();
+        assert!((value - 1.0 / 3.0).abs() < 1e-13);
+        assert_eq!(rational_extrapolation(&x, &y, 2.0).unwrap(), (y[1], 0.0));
+
+        // Close to a pole the rational extrapolation beats the polynomial one
+        let g = |x: f64| 1.0 / (x + 0.1);
+        let x = [0.2, 0.4, 0.6, 0.8, 1.0];
+        let y: Vec<f64> = x.iter().map(|&x| g(x)).collect();
+        let (rational, rational_error) = rational_extrapolation(&x, &y, 0.0).unwrap();
+        let (polynomial, _) = neville(&x, &y, 0.0).unwrap();
+        assert!((rational - 10.0).abs() < 1e-9 && rational_error < 1e-6);
+        assert!((polynomial - 10.0).abs() > 1.0);
+    }
+
+    #[test]
+    fn test_neville_invalid_input() {
+        assert!(neville(&[], &[], 0.0).is_err());
+        assert!(neville(&[0.0, 1.0], &[1.0], 0.0).is_err());
+        assert!(neville(&[0.0, 0.0], &[1.0, 2.0], 0.5).is_err());
+        assert!(rational_extrapolation(&[0.0, f64::NAN], &[1.0, 2.0], 0.5).is_err());
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{
+        normalize, DuplicatePolicy, ExtrapolationStrategy, InterpolationType, Interpolator,
+        InterpolatorBuilder, NormalizationReport,
+    };
+
+    #[test]
+    fn test_normalize_sorts_and_merges() {
+        let x_values = [2.0, 0.0, 1.0, 2.0, 0.0, 2.0];
+        let y_values = [4.0, 1.0, 2.0, 6.0, 3.0, f64::NAN];
+        let (x, y, report) = normalize(&x_values, &y_values, DuplicatePolicy::Mean).unwrap();
+        assert_eq!(x, vec![0.0, 1.0, 2.0]);
+        assert_eq!(y, vec![2.0, 2.0, 5.0]);
+        assert_eq!(
+            report,
+            NormalizationReport {
+                input_len: 6,
+                output_len: 3,
+                reordered: true,
+                duplicates: vec![(0.0, 2), (2.0, 3)],
+            }
+        );
+
+        // First and last follow the input order, missing values are skipped
+        let (_, first, _) = normalize(&x_values, &y_values, DuplicatePolicy::First).unwrap();
+        let (_, last, _) = normalize(&x_values, &y_values, DuplicatePolicy::Last).unwrap();
+        assert_eq!(first, vec![1.0, 2.0, 4.0]);
+        assert_eq!(last, vec![3.0, 2.0, 6.0]);
+        assert!(normalize(&x_values, &y_values, DuplicatePolicy::Error).is_err());
+
+        let (_, _, report) = normalize(&[0.0, 1.0], &[1.0, 2.0], DuplicatePolicy::Error).unwrap();
+        assert!(!report.reordered && report.duplicates.is_empty());
+
+        // Signed zeros are equal, so they are merged without being reordered
+        let (x, y, report) =
+            normalize(&[0.0, -0.0, 1.0], &[1.0, 2.0, 3.0], DuplicatePolicy::First).unwrap();
+        assert_eq!(x, vec![0.0, 1.0]);
+        assert_eq!(y, vec![1.0, 3.0]);
+        assert!(!report.reordered);
+        assert_eq!(report.duplicates, vec![(0.0, 2)]);
+    }
+
+    #[test]
+    fn test_builder_normalization() {
+        let (interpolator, report) = InterpolatorBuilder::new(InterpolationType::Linear)
+            .data(vec![3.0, 1.0, 2.0, 1.0], vec![9.0, 0.0, 4.0, 2.0])
+            .normalize(DuplicatePolicy::Mean)
+            .build_with_report()
+            .unwrap();
+        assert_eq!(interpolator.interpolate(1.0), 1.0);
+        assert_eq!(interpolator.interpolate(2.5), 6.5);
+        assert!(report.reordered);
+        assert_eq!(report.duplicates, vec![(1.0, 2)]);
+
+        // Without normalization unsorted data is rejected
+        assert!(InterpolatorBuilder::new(InterpolationType::Linear)
+            .data(vec![3.0, 1.0, 2.0], vec![9.0, 1.0, 4.0])
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "x_values must be strictly increasing")]
+    fn test_duplicates_are_rejected() {
+        Interpolator::new(
+            vec![0.0, 1.0, 1.0, 2.0],
+            vec![0.0, 1.0, 2.0, 3.0],
+            InterpolationType::Cubic,
+            ExtrapolationStrategy::None,
+        );
+    }
+}
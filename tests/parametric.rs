@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::ParametricSpline;
+    use std::f64::consts::PI;
+
+    fn circle(radius: f64, n: usize) -> Vec<[f64; 2]> {
+        (0..=n)
+            .map(|i| {
+                let angle = PI * i as f64 / n as f64;
+                [radius * angle.cos(), radius * angle.sin()]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_straight_line() {
+        let points = [
+            [0.0, 0.0, 0.0],
+            [1.0, 2.0, 2.0],
+            [2.0, 4.0, 4.0],
+            [4.0, 8.0, 8.0],
+        ];
+        let spline = ParametricSpline::new(&points).unwrap();
+        assert!((spline.length() - 12.0).abs() < 1e-12);
+        assert_eq!(spline.arc_lengths().len(), 4);
+        let position = spline.position(4.5);
+        for (k, expected) in [1.5, 3.0, 3.0].iter().enumerate() {
+            assert!((position[k] - expected).abs() < 1e-12);
+        }
+        let tangent = spline.tangent(7.0);
+        assert!((tangent[0] - 1.0 / 3.0).abs() < 1e-12);
+        assert!(spline.curvature(7.0).abs() < 1e-12);
+        assert_eq!(spline.normal(7.0), [0.0; 3]);
+    }
+
+    #[test]
+    fn test_half_circle() {
+        let radius = 2.0;
+        let spline = ParametricSpline::new(&circle(radius, 32)).unwrap();
+        assert!((spline.length() - PI * radius).abs() < 1e-4);
+        for &s in &[1.0, 2.5, 4.0] {
+            // Points at arc length s lie on the circle at angle s / radius
+            let [x, y] = spline.position(s);
+            assert!((x.hypot(y) - radius).abs() < 1e-4);
+            assert!((y.atan2(x) - s / radius).abs() < 1e-4);
+
+            let tangent = spline.tangent(s);
+            let normal = spline.normal(s);
+            assert!((tangent[0].hypot(tangent[1]) - 1.0).abs() < 1e-12);
+            assert!((tangent[0] * normal[0] + tangent[1] * normal[1]).abs() < 1e-12);
+            // The normal points towards the centre
+            assert!(normal[0] * x + normal[1] * y < -0.99 * radius);
+            assert!((spline.curvature(s) - 1.0 / radius).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_arc_length_parameterisation() {
+        let points = [[0.0, 0.0], [1.0, 3.0], [4.0, 3.5], [6.0, 0.0], [7.0, -2.0]];
+        let spline = ParametricSpline::new(&points).unwrap();
+        // Small steps along the curve have the length of the step in arc length
+        let step = 1e-3;
+        for i in 1..10 {
+            let s = spline.length() * i as f64 / 10.0;
+            let [x0, y0] = spline.position(s);
+            let [x1, y1] = spline.position(s + step);
+            assert!(((x1 - x0).hypot(y1 - y0) - step).abs() < 1e-8);
+        }
+        // The curve passes through the points at their arc lengths
+        for (point, &s) in points.iter().zip(spline.arc_lengths()) {
+            let position = spline.position(s);
+            assert!((position[0] - point[0]).abs() < 1e-9 && (position[1] - point[1]).abs() < 1e-9);
+        }
+        assert!(ParametricSpline::new(&[[0.0, 0.0], [0.0, 0.0]]).is_err());
+    }
+}
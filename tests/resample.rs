@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{
+        aggregate, geomspace, linspace, logspace, resample, Aggregation, ExtrapolationStrategy,
+        InterpolationType,
+    };
+    const EPSILON: f64 = 1e-12;
+
+    #[test]
+    fn test_resample_onto_grid() {
+        let x_values = [0.0, 0.7, 1.1, 2.9, 4.0];
+        let y_values: Vec<f64> = x_values.iter().map(|x| 3.0 * x - 1.0).collect();
+        let grid = linspace(0.0, 5.0, 11);
+        let resampled = resample(
+            &x_values,
+            &y_values,
+            &grid,
+            InterpolationType::Linear,
+            ExtrapolationStrategy::ExtendSpline,
+        )
+        .unwrap();
+        assert_eq!(resampled.len(), grid.len());
+        for (&x, &y) in grid.iter().zip(&resampled) {
+            assert!((y - (3.0 * x - 1.0)).abs() < 1e-9);
+        }
+
+        // Invalid input is reported instead of panicking
+        let linear = InterpolationType::Linear;
+        let none = ExtrapolationStrategy::None;
+        assert!(resample(&x_values, &y_values, &grid, linear, none).is_err());
+        assert!(resample(&x_values, &y_values[..4], &[1.0], linear, none).is_err());
+        assert!(resample(&[0.0, f64::NAN], &[1.0, 2.0], &[0.0], linear, none).is_err());
+        assert!(resample(&x_values, &y_values, &[f64::NAN], linear, none).is_err());
+        let bounded = InterpolationType::Bounded {
+            lower: 0.0,
+            upper: 1.0,
+        };
+        assert!(resample(&x_values, &y_values, &[1.0], bounded, none).is_err());
+        assert!(aggregate(&x_values, &y_values, &[1.0], Aggregation::Mean).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_bins() {
+        let x_values = [0.1, 0.5, 0.9, 1.0, 1.5, 3.2, 4.0, 5.0];
+        let y_values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let grid = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let mean = aggregate(&x_values, &y_values, &grid, Aggregation::Mean).unwrap();
+        assert_eq!(mean.len(), 4);
+        assert!((mean[0] - 2.0).abs() < EPSILON);
+        assert!((mean[1] - 4.5).abs() < EPSILON);
+        assert!(mean[2].is_nan());
+        // The right edge of the grid belongs to the last bin, points beyond it are ignored
+        assert!((mean[3] - 6.5).abs() < EPSILON);
+
+        let sum = aggregate(&x_values, &y_values, &grid, Aggregation::Sum).unwrap();
+        assert_eq!(sum, vec![6.0, 9.0, 0.0, 13.0]);
+
+        // First and last follow x, not the order of the observations
+        let x_values = [0.9, 0.1, 0.5];
+        let y_values = [3.0, 1.0, 2.0];
+        let first = aggregate(&x_values, &y_values, &grid, Aggregation::First).unwrap();
+        let last = aggregate(&x_values, &y_values, &grid, Aggregation::Last).unwrap();
+        assert_eq!(first[0], 1.0);
+        assert_eq!(last[0], 3.0);
+        assert!(first[1].is_nan() && last[1].is_nan());
+    }
+
+    #[test]
+    fn test_grid_helpers() {
+        assert_eq!(linspace(1.0, 2.0, 5), vec![1.0, 1.25, 1.5, 1.75, 2.0]);
+        assert_eq!(linspace(1.0, 2.0, 1), vec![1.0]);
+        assert!(linspace(1.0, 2.0, 0).is_empty());
+
+        let decades = logspace(-2.0, 1.0, 4, 10.0);
+        for (point, expected) in decades.iter().zip([0.01, 0.1, 1.0, 10.0]) {
+            assert!((point - expected).abs() < EPSILON * expected);
+        }
+
+        let geometric = geomspace(-1.0, -16.0, 5);
+        assert_eq!(geometric[0], -1.0);
+        assert_eq!(geometric[4], -16.0);
+        for (point, expected) in geometric.iter().zip([-1.0, -2.0, -4.0, -8.0, -16.0]) {
+            assert!((point - expected).abs() < 1e-12 * expected.abs());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_geomspace_crossing_zero() {
+        geomspace(-1.0, 1.0, 3);
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::TabulatedDistribution;
+
+    /// Deterministic uniform source on [0, 1) from a linear congruential generator
+    fn uniform_source(seed: u64) -> impl FnMut() -> f64 {
+        let mut state = seed;
+        move || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        }
+    }
+
+    #[test]
+    fn test_quantiles_invert_cdf() {
+        let x = [0.0, 1.0, 2.0, 4.0, 5.0];
+        let cdf = [0.0, 0.1, 0.5, 0.9, 1.0];
+        let distribution = TabulatedDistribution::from_cdf(&x, &cdf).unwrap();
+        assert_eq!(distribution.support(), (0.0, 5.0));
+        for (x, c) in x.iter().zip(&cdf) {
+            assert_eq!(distribution.quantile(*c).unwrap(), *x);
+        }
+        for i in 1..100 {
+            let p = i as f64 / 100.0;
+            let q = distribution.quantile(p).unwrap();
+            assert!((distribution.cdf(q) - p).abs() < 1e-12);
+        }
+        assert_eq!(distribution.cdf(-1.0), 0.0);
+        assert_eq!(distribution.cdf(6.0), 1.0);
+
+        // A flat part of the CDF has no probability, its level maps to the left end
+        let flat =
+            TabulatedDistribution::from_cdf(&[0.0, 1.0, 2.0, 3.0], &[0.0, 0.5, 0.5, 1.0]).unwrap();
+        assert_eq!(flat.quantile(0.5).unwrap(), 1.0);
+        assert!(flat.quantile(0.5 + 1e-9).unwrap() > 2.0);
+    }
+
+    #[test]
+    fn test_sampling_from_pdf() {
+        // Exponential density with rate 2 on [0, 10], unnormalised
+        let x: Vec<f64> = (0..=2000).map(|i| i as f64 * 0.005).collect();
+        let pdf: Vec<f64> = x.iter().map(|x| 3.0 * (-2.0 * x).exp()).collect();
+        let distribution = TabulatedDistribution::from_pdf(&x, &pdf).unwrap();
+        let median = distribution.quantile(0.5).unwrap();
+        assert!((median - 2f64.ln() / 2.0).abs() < 1e-4);
+        assert!((distribution.cdf(1.0) - (1.0 - (-2.0f64).exp())).abs() < 1e-4);
+
+        let samples = distribution.samples(20_000, &mut uniform_source(42));
+        assert!(samples.iter().all(|s| (0.0..=10.0).contains(s)));
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert!((mean - 0.5).abs() < 0.02);
+        let below_median = samples.iter().filter(|&&s| s < median).count();
+        assert!((below_median as f64 / 20_000.0 - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_tabulated_distribution_invalid_input() {
+        assert!(TabulatedDistribution::from_cdf(&[0.0, 1.0], &[0.0]).is_err());
+        assert!(TabulatedDistribution::from_cdf(&[0.0, 1.0, 2.0], &[0.0, 0.6, 0.4]).is_err());
+        assert!(TabulatedDistribution::from_cdf(&[0.0, 1.0], &[0.5, 0.5]).is_err());
+        assert!(TabulatedDistribution::from_cdf(&[1.0, 0.0], &[0.0, 1.0]).is_err());
+        assert!(TabulatedDistribution::from_pdf(&[0.0, 1.0], &[1.0, -1.0]).is_err());
+        assert!(TabulatedDistribution::from_pdf(&[0.0, 1.0], &[0.0, 0.0]).is_err());
+
+        let distribution = TabulatedDistribution::from_cdf(&[0.0, 1.0], &[0.0, 1.0]).unwrap();
+        assert!(distribution.quantile(1.5).is_err());
+        assert!(distribution.quantile(f64::NAN).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of [0, 1]")]
+    fn test_sampling_checks_uniform_source() {
+        let distribution = TabulatedDistribution::from_cdf(&[0.0, 1.0], &[0.0, 1.0]).unwrap();
+        distribution.sample(&mut || 2.0);
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{
+        CatmullRomParameterization, ExtrapolationStrategy, InterpolationType, Interpolator,
+    };
+    const EPSILON: f64 = 1e-9;
+
+    fn build(
+        y_values: Vec<f64>,
+        interpolation_type: InterpolationType,
+        extrap_strategy: ExtrapolationStrategy,
+    ) -> Interpolator {
+        Interpolator::new(
+            vec![0.0, 0.5, 1.0, 2.0, 5.0, 10.0],
+            y_values,
+            interpolation_type,
+            extrap_strategy,
+        )
+    }
+
+    fn assert_matches_bumped_curves(
+        interpolation_type: fn() -> InterpolationType,
+        extrap_strategy: fn() -> ExtrapolationStrategy,
+    ) {
+        let y_values = vec![0.01, 0.015, 0.02, 0.022, 0.03, 0.028];
+        let interpolator = build(y_values.clone(), interpolation_type(), extrap_strategy());
+        let x_values = [-1.0, 0.2, 0.75, 1.5, 3.0, 7.5, 10.0, 12.0];
+        let sensitivities = interpolator.node_sensitivities_batch(&x_values);
+        for (&x, weights) in x_values.iter().zip(&sensitivities) {
+            // Every interpolant reproduces constants
+            assert!((weights.iter().sum::<f64>() - 1.0).abs() < EPSILON);
+            for i in 0..y_values.len() {
+                let mut bumped = y_values.clone();
+                bumped[i] += 1.0;
+                let expected = build(bumped, interpolation_type(), extrap_strategy())
+                    .interpolate(x)
+                    - interpolator.interpolate(x);
+                assert!(
+                    (weights[i] - expected).abs() < EPSILON,
+                    "Sensitivity to node {} at x = {}: expected {}, got {}",
+                    i,
+                    x,
+                    expected,
+                    weights[i]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_linear_sensitivities() {
+        let interpolator = build(
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            InterpolationType::Linear,
+            ExtrapolationStrategy::None,
+        );
+        assert_eq!(
+            interpolator.node_sensitivities(0.75),
+            vec![0.0, 0.5, 0.5, 0.0, 0.0, 0.0]
+        );
+        assert_matches_bumped_curves(
+            || InterpolationType::Linear,
+            || ExtrapolationStrategy::ExtendSpline,
+        );
+    }
+
+    #[test]
+    fn test_constant_sensitivities() {
+        assert_matches_bumped_curves(
+            || InterpolationType::ConstantBackward,
+            || ExtrapolationStrategy::Constant,
+        );
+        assert_matches_bumped_curves(
+            || InterpolationType::ConstantForward,
+            || ExtrapolationStrategy::Constant,
+        );
+    }
+
+    #[test]
+    fn test_spline_sensitivities() {
+        assert_matches_bumped_curves(
+            || InterpolationType::Cubic,
+            || ExtrapolationStrategy::ExtendSpline,
+        );
+        assert_matches_bumped_curves(
+            || InterpolationType::Cubic,
+            || ExtrapolationStrategy::Constant,
+        );
+        assert_matches_bumped_curves(
+            || InterpolationType::Quadratic,
+            || ExtrapolationStrategy::ExtendSpline,
+        );
+        assert_matches_bumped_curves(
+            || InterpolationType::Cardinal {
+                tension: 0.3,
+                parameterization: CatmullRomParameterization::Uniform,
+            },
+            || ExtrapolationStrategy::ExtendSpline,
+        );
+        assert_matches_bumped_curves(
+            || InterpolationType::Cardinal {
+                tension: 0.0,
+                parameterization: CatmullRomParameterization::Uniform,
+            },
+            || ExtrapolationStrategy::Constant,
+        );
+        assert_matches_bumped_curves(
+            || InterpolationType::Tension(2.5),
+            || ExtrapolationStrategy::ExtendSpline,
+        );
+    }
+}
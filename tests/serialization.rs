@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{
+        CatmullRomParameterization, ExtrapolationStrategy, GapPolicy, InterpolationType,
+        Interpolator, InterpolatorBuilder,
+    };
+    use numerics_rs::root_finding::ConvergenceLog;
+
+    fn interpolation_types() -> Vec<InterpolationType> {
+        vec![
+            InterpolationType::Linear,
+            InterpolationType::Quadratic,
+            InterpolationType::Cubic,
+            InterpolationType::ConstantBackward,
+            InterpolationType::ConstantForward,
+            InterpolationType::Cardinal {
+                tension: 0.1,
+                parameterization: CatmullRomParameterization::Centripetal,
+            },
+            InterpolationType::Tension(1.0 / 3.0),
+            InterpolationType::ConvexityPreserving,
+        ]
+    }
+
+    #[test]
+    fn test_string_forms() {
+        for interpolation_type in interpolation_types() {
+            let text = interpolation_type.to_string();
+            assert_eq!(text.parse::<InterpolationType>(), Ok(interpolation_type));
+        }
+        assert_eq!(InterpolationType::Tension(2.5).to_string(), "Tension(2.5)");
+        assert_eq!(
+            "Cardinal( 0.5 , Chordal )".parse::<InterpolationType>(),
+            Ok(InterpolationType::Cardinal {
+                tension: 0.5,
+                parameterization: CatmullRomParameterization::Chordal
+            })
+        );
+        assert!("Tension".parse::<InterpolationType>().is_err());
+        assert!("Spline".parse::<InterpolationType>().is_err());
+        for extrap_strategy in [
+            ExtrapolationStrategy::None,
+            ExtrapolationStrategy::Constant,
+            ExtrapolationStrategy::ExtendSpline,
+        ] {
+            assert_eq!(
+                extrap_strategy.to_string().parse::<ExtrapolationStrategy>(),
+                Ok(extrap_strategy)
+            );
+        }
+    }
+
+    #[test]
+    fn test_interpolator_round_trip() {
+        let x_values = vec![0.0, 0.1, 0.35, 1.0, 2.5, 1e6];
+        let y_values = vec![1e-300, -2.0 / 3.0, 0.7, 1e17, 3.0, 4.0];
+        for interpolation_type in interpolation_types() {
+            let interpolator = Interpolator::new(
+                x_values.clone(),
+                y_values.clone(),
+                interpolation_type,
+                ExtrapolationStrategy::Constant,
+            );
+            let restored = Interpolator::from_json(&interpolator.to_json()).unwrap();
+            assert_eq!(restored.to_json(), interpolator.to_json());
+            for &x in &[-1.0, 0.05, 0.2, 0.9, 3.0, 1e5, 2e6] {
+                assert_eq!(
+                    restored.interpolate(x).to_bits(),
+                    interpolator.interpolate(x).to_bits()
+                );
+            }
+        }
+
+        // Gaps extending to infinity are kept
+        let interpolator = InterpolatorBuilder::new(InterpolationType::Linear)
+            .data(vec![0.0, 1.0, 2.0, 3.0], vec![f64::NAN, 1.0, 2.0, 3.0])
+            .gap_policy(GapPolicy::Keep)
+            .build()
+            .unwrap();
+        let restored = Interpolator::from_json(&interpolator.to_json()).unwrap();
+        assert_eq!(restored.gaps(), &[(f64::NEG_INFINITY, 1.0)]);
+    }
+
+    #[test]
+    fn test_invalid_documents() {
+        let json = Interpolator::new(
+            vec![0.0, 1.0, 2.0],
+            vec![0.0, 1.0, 4.0],
+            InterpolationType::Cubic,
+            ExtrapolationStrategy::None,
+        )
+        .to_json();
+        assert!(Interpolator::from_json(&json.replace("\"version\":1", "\"version\":2")).is_err());
+        assert!(Interpolator::from_json(&json.replace("\"c\":[", "\"c\":[\"oops\",")).is_err());
+        assert!(Interpolator::from_json(&json.replace("\"d\":[", "\"d\":[]")).is_err());
+        assert!(Interpolator::from_json(&json[..json.len() - 1]).is_err());
+        assert!(ConvergenceLog::from_json(&json).is_err());
+
+        // Deep nesting is rejected before it can overflow the stack
+        let nested = format!("{}{}", "[".repeat(100_000), "]".repeat(100_000));
+        let error = Interpolator::from_json(&nested).unwrap_err();
+        assert!(
+            error.contains("Nesting deeper than 128 levels"),
+            "{}",
+            error
+        );
+        assert!(ConvergenceLog::from_json(&"{\"a\":".repeat(100_000)).is_err());
+    }
+
+    #[test]
+    fn test_convergence_log_round_trip() {
+        let mut log = ConvergenceLog::new();
+        log.add_entry(1, Box::new([0.0, 1.0]), Box::new([-1.0, f64::INFINITY]));
+        log.add_entry(2, Box::new([0.5]), Box::new([f64::NAN]));
+        let json = log.to_json();
+        assert!(json.contains("\"NaN\"") && json.contains("\"Infinity\""));
+
+        let restored = ConvergenceLog::from_json(&json).unwrap();
+        let entries = restored.get_entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].iteration, 1);
+        assert_eq!(&*entries[0].x, &[0.0, 1.0]);
+        assert_eq!(&*entries[0].fx, &[-1.0, f64::INFINITY]);
+        assert!(entries[1].fx[0].is_nan());
+        assert_eq!(restored.to_json(), json);
+    }
+}
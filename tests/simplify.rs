@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{simplify, ExtrapolationStrategy, InterpolationType, Interpolator};
+
+    fn max_error(
+        x: &[f64],
+        y: &[f64],
+        knots: (Vec<f64>, Vec<f64>),
+        kind: InterpolationType,
+    ) -> f64 {
+        let interpolator = Interpolator::new(knots.0, knots.1, kind, ExtrapolationStrategy::None);
+        x.iter()
+            .zip(y)
+            .map(|(&x, &y)| (interpolator.interpolate(x) - y).abs())
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_simplify_linear_keeps_corners() {
+        let x: Vec<f64> = (0..=40).map(|i| i as f64 * 0.5).collect();
+        let y: Vec<f64> = x
+            .iter()
+            .map(|&x| if x < 10.0 { 10.0 - x } else { 2.0 * (x - 10.0) })
+            .collect();
+        let (kept_x, kept_y) = simplify(&x, &y, InterpolationType::Linear, 1e-12).unwrap();
+        assert_eq!(kept_x, vec![0.0, 10.0, 20.0]);
+        assert_eq!(kept_y, vec![10.0, 0.0, 20.0]);
+
+        // A loose tolerance drops the small wiggles but keeps the error bounded
+        let noisy: Vec<f64> = y
+            .iter()
+            .enumerate()
+            .map(|(i, y)| y + 0.01 * (i as f64).sin())
+            .collect();
+        let knots = simplify(&x, &noisy, InterpolationType::Linear, 0.05).unwrap();
+        assert!(knots.0.len() < 6);
+        assert!(max_error(&x, &noisy, knots, InterpolationType::Linear) <= 0.05);
+    }
+
+    #[test]
+    fn test_simplify_splines_within_tolerance() {
+        let x: Vec<f64> = (0..1000).map(|i| i as f64 / 999.0 * 6.0).collect();
+        let y: Vec<f64> = x.iter().map(|x| x.sin() + 0.1 * x * x).collect();
+        // A small fraction of the 1000 points is enough for every type
+        for (kind, tolerance, limit) in [
+            (InterpolationType::Cubic, 1e-6, 100),
+            (InterpolationType::MonotoneCubic, 1e-4, 100),
+            (InterpolationType::Tension(1.0), 1e-5, 150),
+            (InterpolationType::Linear, 1e-3, 100),
+        ] {
+            let knots = simplify(&x, &y, kind, tolerance).unwrap();
+            assert_eq!(knots.0[0], 0.0);
+            assert_eq!(knots.0[knots.0.len() - 1], 6.0);
+            assert!(knots.0.len() < limit, "{} kept {}", kind, knots.0.len());
+            assert!(max_error(&x, &y, knots, kind) <= tolerance);
+        }
+    }
+
+    #[test]
+    fn test_simplify_baseline_types() {
+        // Steps and the quadratic spline don't reproduce all of their knots, only the dropped points count
+        let x: Vec<f64> = (0..20).map(|i| i as f64).collect();
+        let y: Vec<f64> = x.iter().map(|x| (x / 3.0).sin()).collect();
+        for kind in [
+            InterpolationType::Linear,
+            InterpolationType::Quadratic,
+            InterpolationType::Cubic,
+            InterpolationType::ConstantBackward,
+            InterpolationType::ConstantForward,
+        ] {
+            let knots = simplify(&x, &y, kind, 0.6).unwrap();
+            assert_eq!(knots.0[0], 0.0);
+            assert_eq!(knots.0[knots.0.len() - 1], 19.0);
+            assert!(knots.0.len() < x.len(), "{} kept every point", kind);
+            let interpolator =
+                Interpolator::new(knots.0.clone(), knots.1, kind, ExtrapolationStrategy::None);
+            for (&x, &y) in x.iter().zip(&y) {
+                if !knots.0.contains(&x) {
+                    assert!((interpolator.interpolate(x) - y).abs() <= 0.6);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_simplify_invalid_input() {
+        let kind = InterpolationType::Cubic;
+        assert!(simplify(&[0.0], &[1.0], kind, 1e-3).is_err());
+        assert!(simplify(&[0.0, 1.0], &[1.0], kind, 1e-3).is_err());
+        assert!(simplify(&[1.0, 0.0], &[1.0, 2.0], kind, 1e-3).is_err());
+        assert!(simplify(&[0.0, 1.0], &[1.0, f64::NAN], kind, 1e-3).is_err());
+        assert!(simplify(&[0.0, 1.0], &[1.0, 2.0], kind, -1.0).is_err());
+
+        // Two points are already minimal
+        let knots = simplify(&[0.0, 1.0], &[1.0, 2.0], kind, 0.0).unwrap();
+        assert_eq!(knots, (vec![0.0, 1.0], vec![1.0, 2.0]));
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{
+        resample_rational, ExtrapolationStrategy, InterpolationType, Interpolator, SincWindow,
+    };
+    use std::f64::consts::PI;
+
+    fn signal(t: f64) -> f64 {
+        (2.0 * PI * 0.1 * t).sin() + 0.5 * (2.0 * PI * 0.23 * t + 0.3).cos()
+    }
+
+    fn interpolator(values: Vec<f64>, kind: InterpolationType) -> Interpolator {
+        let x = (0..values.len()).map(|i| i as f64).collect();
+        Interpolator::new(x, values, kind, ExtrapolationStrategy::ExtendSpline)
+    }
+
+    #[test]
+    fn test_sinc_band_limited_signal() {
+        let values: Vec<f64> = (0..200).map(|i| signal(i as f64)).collect();
+        let max_error = |interpolator: &Interpolator| {
+            (0..=500)
+                .map(|i| 50.0 + i as f64 * 0.2 + 0.037)
+                .map(|t| (interpolator.interpolate(t) - signal(t)).abs())
+                .fold(0.0, f64::max)
+        };
+        let cubic = max_error(&interpolator(values.clone(), InterpolationType::Cubic));
+        let lanczos = max_error(&interpolator(
+            values.clone(),
+            InterpolationType::Sinc(SincWindow::Lanczos(8)),
+        ));
+        let kaiser = max_error(&interpolator(
+            values,
+            InterpolationType::Sinc(SincWindow::Kaiser {
+                half_width: 16,
+                beta: 8.0,
+            }),
+        ));
+        assert!(lanczos < cubic / 2.0, "{} {}", lanczos, cubic);
+        assert!(kaiser < 1e-3, "{}", kaiser);
+    }
+
+    #[test]
+    fn test_sinc_interpolates_samples() {
+        let values = vec![1.0, -2.0, 0.5, 3.0, 0.0, 1.5, -1.0];
+        let x: Vec<f64> = (0..7).map(|i| 2.0 + 0.5 * i as f64).collect();
+        let kind = InterpolationType::Sinc(SincWindow::Lanczos(3));
+        let sinc = Interpolator::new(
+            x.clone(),
+            values.clone(),
+            kind,
+            ExtrapolationStrategy::Constant,
+        );
+        for (x, y) in x.iter().zip(&values) {
+            assert!((sinc.interpolate(*x) - y).abs() < 1e-12);
+        }
+        // The interpolant is linear in the samples
+        for t in [2.1, 3.3, 4.9, 5.0] {
+            let weights = sinc.node_sensitivities(t);
+            let value: f64 = weights.iter().zip(&values).map(|(w, y)| w * y).sum();
+            assert!((value - sinc.interpolate(t)).abs() < 1e-12);
+            assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        }
+        assert_eq!(kind.to_string(), "Sinc(Lanczos, 3)");
+        let kaiser = InterpolationType::Sinc(SincWindow::Kaiser {
+            half_width: 4,
+            beta: 6.5,
+        });
+        assert_eq!(
+            kaiser.to_string().parse::<InterpolationType>().unwrap(),
+            kaiser
+        );
+        let copy = Interpolator::from_json(&sinc.to_json()).unwrap();
+        assert_eq!(copy.interpolate(3.3), sinc.interpolate(3.3));
+    }
+
+    #[test]
+    fn test_resample_rational() {
+        let values: Vec<f64> = (0..40).map(|i| signal(i as f64)).collect();
+        let window = SincWindow::Lanczos(5);
+        let sinc = interpolator(values.clone(), InterpolationType::Sinc(window));
+
+        // 3 / 2 resampling puts output sample m at 2m / 3
+        let resampled = resample_rational(&values, 3, 2, window).unwrap();
+        assert_eq!(resampled.len(), 39 * 3 / 2 + 1);
+        for (m, value) in resampled.iter().enumerate() {
+            assert!((value - sinc.interpolate(m as f64 * 2.0 / 3.0)).abs() < 1e-12);
+        }
+        assert_eq!(resample_rational(&values, 2, 2, window).unwrap(), values);
+        let decimated = resample_rational(&values, 1, 4, window).unwrap();
+        assert_eq!(decimated.len(), 10);
+        assert_eq!(decimated[3], values[12]);
+
+        assert!(resample_rational(&values, 0, 2, window).is_err());
+        assert!(resample_rational(&[], 1, 2, window).is_err());
+        assert!(resample_rational(&values, 1, 2, SincWindow::Lanczos(0)).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "equally spaced")]
+    fn test_sinc_requires_uniform_grid() {
+        Interpolator::new(
+            vec![0.0, 1.0, 3.0],
+            vec![1.0, 2.0, 3.0],
+            InterpolationType::Sinc(SincWindow::Lanczos(2)),
+            ExtrapolationStrategy::None,
+        );
+    }
+}
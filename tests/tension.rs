@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{ExtrapolationStrategy, InterpolationType, Interpolator};
+    const EPSILON: f64 = 1e-9;
+
+    fn build(
+        x_values: &[f64],
+        y_values: &[f64],
+        interpolation_type: InterpolationType,
+    ) -> Interpolator {
+        Interpolator::new(
+            x_values.to_vec(),
+            y_values.to_vec(),
+            interpolation_type,
+            ExtrapolationStrategy::None,
+        )
+    }
+
+    fn assert_c1(interpolator: &Interpolator, knots: &[f64]) {
+        let h = 1e-6;
+        for &x in knots {
+            let left = (interpolator.interpolate(x) - interpolator.interpolate(x - h)) / h;
+            let right = (interpolator.interpolate(x + h) - interpolator.interpolate(x)) / h;
+            assert!((left - right).abs() < 1e-4, "Kink at x = {}", x);
+        }
+    }
+
+    #[test]
+    fn test_tension_limits() {
+        let x_values = [0.0, 1.0, 2.5, 3.0, 5.0];
+        let y_values = [0.0, 2.0, 1.0, 3.0, 2.0];
+        let cubic = build(&x_values, &y_values, InterpolationType::Cubic);
+        let linear = build(&x_values, &y_values, InterpolationType::Linear);
+        let relaxed = build(&x_values, &y_values, InterpolationType::Tension(0.0));
+        let tight = build(&x_values, &y_values, InterpolationType::Tension(2000.0));
+        let infinite = build(
+            &x_values,
+            &y_values,
+            InterpolationType::Tension(f64::INFINITY),
+        );
+        assert_c1(&relaxed, &x_values[1..4]);
+        for (&x, &y) in x_values.iter().zip(&y_values) {
+            assert!((relaxed.interpolate(x) - y).abs() < EPSILON);
+            assert!((tight.interpolate(x) - y).abs() < EPSILON);
+        }
+        for i in 0..=50 {
+            let x = i as f64 / 10.0;
+            // No tension gives back the natural cubic spline, a large one approaches the broken line
+            assert!((relaxed.interpolate(x) - cubic.interpolate(x)).abs() < EPSILON);
+            assert!((tight.interpolate(x) - linear.interpolate(x)).abs() < 5e-3);
+            assert!((infinite.interpolate(x) - linear.interpolate(x)).abs() < EPSILON);
+            assert_eq!(infinite.node_sensitivities(x), linear.node_sensitivities(x));
+        }
+    }
+
+    #[test]
+    fn test_tension_reduces_overshoot() {
+        let x_values = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_values = [0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        let overshoot = |sigma: f64| {
+            let interpolator = build(&x_values, &y_values, InterpolationType::Tension(sigma));
+            assert_c1(&interpolator, &x_values[1..5]);
+            (0..=500)
+                .map(|i| interpolator.interpolate(i as f64 / 100.0))
+                .fold(0.0, |m: f64, y| m.max(y - 1.0).max(-y))
+        };
+        let (loose, medium, tight) = (overshoot(0.5), overshoot(5.0), overshoot(50.0));
+        assert!(tight < medium && medium < loose);
+        assert!(tight < 1e-2);
+    }
+
+    #[test]
+    fn test_tension_inverse() {
+        let x_values = [0.0, 1.0, 2.0, 4.0];
+        let y_values = [0.0, 0.2, 1.5, 1.7];
+        let interpolator = build(&x_values, &y_values, InterpolationType::Tension(10.0));
+        for &y in &[0.1, 0.7, 1.6] {
+            let x = interpolator.inverse(y).unwrap();
+            assert!((interpolator.interpolate(x) - y).abs() < EPSILON);
+        }
+        // Without tension the same data overshoots and is no longer invertible
+        let cubic = build(&x_values, &y_values, InterpolationType::Tension(0.0));
+        assert!(cubic.inverse(0.7).is_err());
+    }
+
+    #[test]
+    fn test_convexity_preserving() {
+        // Convex data with a steep start, the natural cubic spline wiggles after it
+        let x_values = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let y_values = [10.0, 2.0, 1.0, 0.6, 0.4, 0.3, 0.25];
+        let convex = build(&x_values, &y_values, InterpolationType::ConvexityPreserving);
+        let cubic = build(&x_values, &y_values, InterpolationType::Cubic);
+        for (&x, &y) in x_values.iter().zip(&y_values) {
+            assert!((convex.interpolate(x) - y).abs() < EPSILON);
+        }
+        assert_c1(&convex, &x_values[1..6]);
+
+        let second_differences = |interpolator: &Interpolator| {
+            let h = 0.01;
+            (1..600)
+                .map(|i| {
+                    let x = i as f64 * h;
+                    interpolator.interpolate(x + h) - 2.0 * interpolator.interpolate(x)
+                        + interpolator.interpolate(x - h)
+                })
+                .fold(f64::INFINITY, f64::min)
+        };
+        assert!(second_differences(&convex) > -1e-12);
+        assert!(second_differences(&cubic) < 0.0);
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::ThinPlateSpline;
+    const EPSILON: f64 = 1e-8;
+
+    fn grid() -> Vec<(f64, f64)> {
+        (0..6)
+            .flat_map(|i| (0..6).map(move |j| (i as f64 * 0.2, j as f64 * 0.3 + 0.05 * i as f64)))
+            .collect()
+    }
+
+    #[test]
+    fn test_thin_plate_interpolation() {
+        let points = grid();
+        let surface = |x: f64, y: f64| (2.0 * x).sin() * (1.0 + y * y);
+        let values: Vec<f64> = points.iter().map(|&(x, y)| surface(x, y)).collect();
+        let spline = ThinPlateSpline::new(&points, &values, 0.0).unwrap();
+
+        for (&(x, y), &z) in points.iter().zip(&values) {
+            assert!((spline.evaluate(x, y) - z).abs() < EPSILON);
+        }
+        assert!((spline.evaluate(0.5, 0.8) - surface(0.5, 0.8)).abs() < 0.05);
+
+        // Gradient matches central finite differences
+        let (x, y, h) = (0.45, 0.7, 1e-6);
+        let (dx, dy) = spline.gradient(x, y);
+        let fd_x = (spline.evaluate(x + h, y) - spline.evaluate(x - h, y)) / (2.0 * h);
+        let fd_y = (spline.evaluate(x, y + h) - spline.evaluate(x, y - h)) / (2.0 * h);
+        assert!((dx - fd_x).abs() < 1e-6);
+        assert!((dy - fd_y).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_thin_plate_affine_data() {
+        let points = grid();
+        let values: Vec<f64> = points
+            .iter()
+            .map(|&(x, y)| 1.0 + 2.0 * x - 3.0 * y)
+            .collect();
+        let spline = ThinPlateSpline::new(&points, &values, 0.5).unwrap();
+        let [a0, a1, a2] = spline.affine();
+        assert!((a0 - 1.0).abs() < EPSILON);
+        assert!((a1 - 2.0).abs() < EPSILON);
+        assert!((a2 + 3.0).abs() < EPSILON);
+        assert!((spline.evaluate(3.0, -1.0) - 10.0).abs() < EPSILON);
+        let (dx, dy) = spline.gradient(0.3, 0.3);
+        assert!((dx - 2.0).abs() < EPSILON);
+        assert!((dy + 3.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_thin_plate_smoothing_cross_validation() {
+        let points = grid();
+        // Smooth surface with deterministic pseudo-random noise
+        let mut state: u64 = 42;
+        let values: Vec<f64> = points
+            .iter()
+            .map(|&(x, y)| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                x * y + 0.1 * ((state >> 11) as f64 / (1u64 << 53) as f64 - 0.5)
+            })
+            .collect();
+        let candidates = [0.0, 1e-4, 1e-3, 1e-2, 1e-1, 1.0];
+        let spline = ThinPlateSpline::cross_validated(&points, &values, &candidates).unwrap();
+        assert!(spline.smoothing() > 0.0);
+
+        let best =
+            ThinPlateSpline::leave_one_out_error(&points, &values, spline.smoothing()).unwrap();
+        for &smoothing in &candidates {
+            let error = ThinPlateSpline::leave_one_out_error(&points, &values, smoothing).unwrap();
+            assert!(best <= error);
+        }
+
+        // The smoothed surface is closer to the noise-free one than the interpolating surface
+        let interpolating = ThinPlateSpline::new(&points, &values, 0.0).unwrap();
+        let error = |s: &ThinPlateSpline| {
+            points
+                .iter()
+                .map(|&(x, y)| (s.evaluate(x, y) - x * y).powi(2))
+                .sum::<f64>()
+        };
+        assert!(error(&spline) < error(&interpolating));
+    }
+
+    #[test]
+    fn test_thin_plate_collinear_points() {
+        let points = vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)];
+        let values = vec![0.0, 1.0, 2.0, 3.0];
+        assert!(ThinPlateSpline::new(&points, &values, 0.0).is_err());
+    }
+}
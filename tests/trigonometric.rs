@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use numerics_rs::interp::{
+        AngularInterpolator, ExtrapolationStrategy, InterpolationType, TrigonometricInterpolator,
+    };
+    use std::f64::consts::PI;
+    const EPSILON: f64 = 1e-9;
+
+    fn seasonality(x: f64) -> f64 {
+        // Yearly cycle with a half-year harmonic, in months
+        2.0 + (2.0 * PI * x / 12.0).sin() - 0.5 * (4.0 * PI * x / 12.0).cos()
+    }
+
+    #[test]
+    fn test_trigonometric_reproduces_harmonics() {
+        let values: Vec<f64> = (0..12).map(|i| seasonality(i as f64)).collect();
+        let interpolator = TrigonometricInterpolator::new(0.0, 12.0, &values).unwrap();
+        for (i, &y) in values.iter().enumerate() {
+            assert!((interpolator.interpolate(i as f64) - y).abs() < EPSILON);
+        }
+        // The data is a trigonometric polynomial of low degree so it is reproduced everywhere, periodically
+        for &x in &[0.3, 5.5, 11.9, -7.25, 30.1] {
+            assert!((interpolator.interpolate(x) - seasonality(x)).abs() < EPSILON);
+        }
+        assert!((interpolator.mean() - 2.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_trigonometric_even_and_odd_samples() {
+        for n in [7, 8] {
+            let values: Vec<f64> = (0..n).map(|i| (i as f64 * 1.3).cos() + i as f64).collect();
+            let interpolator = TrigonometricInterpolator::new(1.0, 2.0, &values).unwrap();
+            for (i, &y) in values.iter().enumerate() {
+                let x = 1.0 + 2.0 * i as f64 / n as f64;
+                assert!((interpolator.interpolate(x) - y).abs() < EPSILON);
+                assert!((interpolator.interpolate(x + 4.0) - y).abs() < EPSILON);
+            }
+        }
+        assert!(TrigonometricInterpolator::new(0.0, 1.0, &[]).is_err());
+        assert!(TrigonometricInterpolator::new(0.0, 0.0, &[1.0]).is_err());
+    }
+
+    #[test]
+    fn test_trigonometric_calculus() {
+        let values: Vec<f64> = (0..12).map(|i| seasonality(i as f64)).collect();
+        let interpolator = TrigonometricInterpolator::new(0.0, 12.0, &values).unwrap();
+        let w = 2.0 * PI / 12.0;
+        let x = 4.2;
+        let first = w * (w * x).cos() + w * (2.0 * w * x).sin();
+        let second = -w * w * (w * x).sin() + 2.0 * w * w * (2.0 * w * x).cos();
+        assert!((interpolator.derivative(x, 0) - seasonality(x)).abs() < EPSILON);
+        assert!((interpolator.derivative(x, 1) - first).abs() < EPSILON);
+        assert!((interpolator.derivative(x, 2) - second).abs() < EPSILON);
+
+        // Over any full period the integral is the mean times the period
+        assert!((interpolator.integral(0.0, 12.0) - 24.0).abs() < EPSILON);
+        assert!((interpolator.integral(3.7, 15.7) - 24.0).abs() < EPSILON);
+        let exact =
+            2.0 * 3.0 + (1.0 - (w * 3.0).cos()) / w - 0.5 * (2.0 * w * 3.0).sin() / (2.0 * w);
+        assert!((interpolator.integral(0.0, 3.0) - exact).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_angular_wraps_around() {
+        let interpolator = AngularInterpolator::new(
+            vec![0.0, 1.0, 2.0, 3.0],
+            vec![2.8, -3.0, -2.6, 3.1],
+            InterpolationType::Linear,
+            ExtrapolationStrategy::None,
+        );
+        // Crossing the wrap goes through pi instead of through zero
+        let midpoint = interpolator.interpolate(0.5);
+        assert!((midpoint - (2.8 + (2.0 * PI - 5.8) / 2.0)).abs() < EPSILON);
+        assert!(midpoint > 2.8 && midpoint <= PI);
+        assert!((interpolator.interpolate(1.25) - (-3.0 + 0.1)).abs() < EPSILON);
+        let wrapped = interpolator.interpolate(2.5);
+        assert!(wrapped.abs() > 2.8 && wrapped > -PI && wrapped <= PI);
+        assert!((interpolator.interpolate(3.0) - 3.1).abs() < EPSILON);
+        assert!((interpolator.interpolate_unwrapped(2.0) - (-2.6 + 2.0 * PI)).abs() < EPSILON);
+    }
+}